@@ -6,7 +6,7 @@ extern crate test;
 use test::{Bencher, black_box};
 
 use crate::sobol::*;
-use crate::sobol::params::JoeKuoD6;
+use crate::sobol::params::PackedParams;
 
 
 /** The number of points to generate per benchmark */
@@ -15,6 +15,9 @@ const N: usize = 128;
 /** The dimensionality of the sequence to generate */
 const DIMS: usize = 10;
 
+/** The dimensionality of high-dimensional sequences to generate */
+const HI_DIMS: usize = 1000;
+
 /** Generates N `f32` points */
 #[bench] fn bench_generate_f32(b: &mut Bencher) {
     generate_points::<f32>(b);
@@ -75,6 +78,45 @@ const DIMS: usize = 10;
     generate_points::<i128>(b);
 }
 
+/** Fills a buffer with N `f32` points */
+#[bench] fn bench_fill_f32(b: &mut Bencher) {
+    fill_points::<f32>(b);
+}
+
+/** Fills a buffer with N `f64` points */
+#[bench] fn bench_fill_f64(b: &mut Bencher) {
+    fill_points::<f64>(b);
+}
+
+/** Fills a buffer with N high-dimensional `f32` points */
+#[bench] fn bench_fill_f32_hidim(b: &mut Bencher) {
+    let mut sobol = Sobol::<f32>::new(HI_DIMS, &PackedParams::standard());
+    let mut buf = vec![0f32; N * HI_DIMS];
+    b.iter(|| {
+        sobol.skip_to(0);
+        black_box(sobol.fill(&mut buf, N))
+    });
+}
+
+/** Fills a buffer with N high-dimensional `f64` points */
+#[bench] fn bench_fill_f64_hidim(b: &mut Bencher) {
+    let mut sobol = Sobol::<f64>::new(HI_DIMS, &PackedParams::standard());
+    let mut buf = vec![0f64; N * HI_DIMS];
+    b.iter(|| {
+        sobol.skip_to(0);
+        black_box(sobol.fill(&mut buf, N))
+    });
+}
+
+/** Fills a buffer with N high-dimensional `f32` points in column-major order */
+#[bench] fn bench_fill_columns_f32_hidim(b: &mut Bencher) {
+    let mut sobol = Sobol::<f32>::new(HI_DIMS, &PackedParams::standard());
+    let mut buf = vec![0f32; N * HI_DIMS];
+    b.iter(|| {
+        sobol.skip_to(0);
+        black_box(sobol.fill_columns(&mut buf, N))
+    });
+}
 
 /**
  * Generates N points for a given type and consumes the results.
@@ -82,6 +124,17 @@ const DIMS: usize = 10;
 fn generate_points<T>(b: &mut Bencher) 
     where T: SobolType, T::IT: LossyFrom<u32>, Sobol<T>: Clone {
 
-    let sobol = Sobol::<T>::new(DIMS, &JoeKuoD6::standard());
+    let sobol = Sobol::<T>::new(DIMS, &PackedParams::standard());
     b.iter(|| black_box(sobol.clone().take(N).collect::<Vec<_>>()));
 }
+
+/**
+ * Generates N points for a given type into a preallocated buffer.
+ */
+fn fill_points<T>(b: &mut Bencher)
+    where T: SobolType + Default + Clone, T::IT: LossyFrom<u32>, Sobol<T>: Clone {
+
+    let sobol = Sobol::<T>::new(DIMS, &PackedParams::standard());
+    let mut buf = vec![T::default(); N * DIMS];
+    b.iter(|| black_box(sobol.clone().fill(&mut buf, N)));
+}
@@ -5,10 +5,20 @@ extern crate test;
 
 use test::{Bencher, black_box};
 
-use crate::sobol::params::JoeKuoD6;
+use crate::sobol::params::{JoeKuoD6, PackedParams};
 
 
-/** Benchmark loading of parameter data */
+/** Benchmark loading and decoding of every dimension of parameter data */
 #[bench] fn bench_params(b: &mut Bencher) {
     b.iter(|| black_box(JoeKuoD6::standard()));
 }
+
+/** Benchmark loading of packed parameter data */
+#[bench] fn bench_params_packed(b: &mut Bencher) {
+    b.iter(|| black_box(PackedParams::standard()));
+}
+
+/** Benchmark loading of the largest packed parameter data */
+#[bench] fn bench_params_packed_extended(b: &mut Bencher) {
+    b.iter(|| black_box(PackedParams::extended()));
+}
@@ -0,0 +1,101 @@
+extern crate libflate;
+
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+use libflate::gzip::Decoder;
+
+
+/** The leading bytes (and format version) of packed parameters */
+const PACKED_MAGIC: &[u8; 5] = b"JKD6\x01";
+
+
+/**
+ * Converts the embedded parameter tables enabled by features from the gzip compressed text format
+ * of Joe and Kuo into the packed binary format read by `params::PackedParams`
+ */
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    for (feature, name) in [("PARAMS_MINIMAL", "new-joe-kuo-6.100"),
+                            ("PARAMS_STANDARD", "new-joe-kuo-6.1000"),
+                            ("PARAMS_EXTENDED", "new-joe-kuo-6.21201")] {
+
+        if env::var_os(format!("CARGO_FEATURE_{}", feature)).is_none() { continue; }
+
+        let src = format!("src/params/data/{}.gz", name);
+        println!("cargo:rerun-if-changed={}", src);
+
+        let mut text = String::new();
+        Decoder::new(File::open(&src).unwrap()).unwrap().read_to_string(&mut text).unwrap();
+        fs::write(Path::new(&out_dir).join(format!("{}.bin", name)), pack(&parse(&text))).unwrap();
+    }
+}
+
+/** Parses the `(s, a, m_i...)` parameters of each dimension (beginning with the second) */
+fn parse(text: &str) -> Vec<(usize, u32, Vec<u32>)> {
+    text.lines().skip(1).filter(|line| !line.trim().is_empty()).enumerate().map(|(i, line)| {
+        let vals = line.split_whitespace().map(|t| t.parse::<u32>().unwrap()).collect::<Vec<_>>();
+        assert_eq!(vals[0] as usize, i + 2, "Dimensions must be consecutive");
+        (vals[1] as usize, vals[2], vals[3 ..].to_vec())
+    }).collect()
+}
+
+/**
+ * Encodes parameters as a header followed by runs of consecutive dimensions of the same degree
+ * `s`. A run is described by its degree (one byte) and number of dimensions (four bytes, little
+ * endian), and the data of all runs follows the descriptions, each beginning on a byte boundary.
+ * Each dimension of a run occupies `s - 1` bits for the coefficients `a` followed by `i` bits
+ * for each initial direction value `m_i`, packed least significant bit first.
+ */
+fn pack(dims: &[(usize, u32, Vec<u32>)]) -> Vec<u8> {
+    let mut runs: Vec<(usize, u32)> = vec![];
+    for (s, _, _) in dims {
+        match runs.last_mut() {
+            Some((run_s, count)) if run_s == s => *count += 1,
+            _ => runs.push((*s, 1))
+        }
+    }
+
+    let mut bytes = PACKED_MAGIC.to_vec();
+    bytes.extend_from_slice(&(runs.len() as u32).to_le_bytes());
+    for (s, count) in runs.iter() {
+        bytes.push(*s as u8);
+        bytes.extend_from_slice(&count.to_le_bytes());
+    }
+
+    let mut dims = dims.iter();
+    for (s, count) in runs.iter() {
+        let mut bits = BitWriter::default();
+        for (_, a, m) in dims.by_ref().take(*count as usize) {
+            assert!(*s >= 1 && *s <= 32 && m.len() == *s && *a >> (s - 1) == 0, "Invalid parameters");
+            bits.write(*a, s - 1);
+            for (i, m) in m.iter().enumerate() {
+                assert!(*m as u64 >> (i + 1) == 0, "Invalid parameters");
+                bits.write(*m, i + 1);
+            }
+        }
+        bytes.extend(bits.bytes);
+    }
+    bytes
+}
+
+/** Accumulates values of arbitrary bit width, least significant bit first */
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    len: usize
+}
+
+impl BitWriter {
+
+    fn write(&mut self, val: u32, width: usize) {
+        for i in 0 .. width {
+            if self.len % 8 == 0 { self.bytes.push(0); }
+            *self.bytes.last_mut().unwrap() |= (((val >> i) & 1) as u8) << (self.len % 8);
+            self.len += 1;
+        }
+    }
+}
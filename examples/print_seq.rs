@@ -1,7 +1,7 @@
 extern crate sobol;
 
 use crate::sobol::*;
-use crate::sobol::params::JoeKuoD6;
+use crate::sobol::params::PackedParams;
 
 
 /** The dimensionality of the sequence to generate */
@@ -21,7 +21,7 @@ fn main() {
     println!(" [ Dimensions ] = {}", DIMS);
     println!(" [ Count      ] = {}", N);
 
-    let params = JoeKuoD6::standard();
+    let params = PackedParams::standard();
     let sobol = Sobol::<ValType>::new(DIMS, &params);
     
     sobol
@@ -1,7 +1,7 @@
 extern crate sobol;
 
 use crate::sobol::*;
-use crate::sobol::params::JoeKuoD6;
+use crate::sobol::params::PackedParams;
 
 use std::iter::Iterator;
 use std::time::Instant;
@@ -39,7 +39,7 @@ fn main() {
 }
 
 fn benchmark() -> usize {
-    let params = JoeKuoD6::standard();
+    let params = PackedParams::standard();
     let sobol = Sobol::<ValType>::new(DIMS, &params);
     let start = Instant::now();
     sobol.take_while(|_p| start.elapsed().as_millis() < MILLIS).count()
@@ -0,0 +1,213 @@
+use crate::{Sobol, SobolType, SobolParams, SobolError, InternalType, Interval, LossyFrom, Scrambling, init_dim_direction_vals};
+use crate::error::{check_config, check_internal_resolution};
+
+use num_traits::{Float, NumCast};
+
+
+/**
+ * Configures and validates a `Sobol` sequence. All options are checked together when the
+ * sequence is built, so invalid configurations are reported as a `SobolError` rather than a panic.
+ *
+ * ```
+ * # #[cfg(feature = "params-minimal")] {
+ * use sobol::{Sobol, Scrambling};
+ * use sobol::params::PackedParams;
+ *
+ * let params = PackedParams::minimal();
+ * let seq = Sobol::<f64>::builder(&params)
+ *     .dims(2)
+ *     .scrambling(Scrambling::Owen(42))
+ *     .bounds(&[(-1.0, 1.0), (0.0, 10.0)])
+ *     .skip_zero()
+ *     .build()
+ *     .unwrap();
+ * # }
+ * ```
+ */
+pub struct SobolBuilder<'a, T: SobolType, P> {
+    params: &'a dyn SobolParams<P>,
+    dim_indices: Vec<usize>,
+    resolution: Option<usize>,
+    internal_resolution: Option<usize>,
+    start: u128,
+    skip: u128,
+    skip_zero: bool,
+    scrambling: Scrambling,
+    bounds: Result<Vec<(T, T)>, SobolError>,
+    interval: Interval
+}
+
+impl<T: SobolType> Sobol<T> {
+
+    /**
+     * Returns a builder for a sequence using the given parameters
+     **/
+    pub fn builder<P>(params: &dyn SobolParams<P>) -> SobolBuilder<'_, T, P> {
+        SobolBuilder {
+            params,
+            dim_indices: vec![],
+            resolution: None,
+            internal_resolution: None,
+            start: 0,
+            skip: 0,
+            skip_zero: false,
+            scrambling: Scrambling::None,
+            bounds: Ok(vec![]),
+            interval: Interval::ClosedOpen
+        }
+    }
+}
+
+impl<'a, T: SobolType, P> SobolBuilder<'a, T, P> {
+
+    /**
+     * Generates the first `dims` dimensions of the sequence. This replaces any subset of
+     * dimensions previously selected.
+     **/
+    pub fn dims(mut self, dims: usize) -> Self {
+        self.dim_indices = (0 .. dims).collect();
+        self
+    }
+
+    /**
+     * Generates only the given (zero-based) dimensions of the sequence, in the order given. This
+     * replaces any number of dimensions previously set.
+     **/
+    pub fn dimensions(mut self, dims: &[usize]) -> Self {
+        self.dim_indices = dims.to_vec();
+        self
+    }
+
+    /**
+     * Sets the number of bits used in the computation of the sequence (see
+     * `Sobol::new_with_resolution`). By default, this is the maximum supported by the type.
+     **/
+    pub fn resolution(mut self, resolution: usize) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /**
+     * Sets the number of bits of the index used in the computation of the sequence, such that it
+     * has `2^internal_resolution` points. This may exceed the resolution, in which case values are
+     * computed as for a higher resolution and truncated (so a `u8` sequence may go on beyond 256
+     * points, repeating values at a lower precision). Types narrower than 64 bits support an
+     * internal resolution of up to 63 bits. By default, this is the resolution.
+     **/
+    pub fn internal_resolution(mut self, internal_resolution: usize) -> Self {
+        self.internal_resolution = Some(internal_resolution);
+        self
+    }
+
+    /**
+     * Sets the zero-based index of the first point to be generated
+     **/
+    pub fn start(mut self, index: u128) -> Self {
+        self.start = index;
+        self
+    }
+
+    /**
+     * Sets the number of points to be discarded from the start of the sequence (in addition to
+     * those preceding the starting index)
+     **/
+    pub fn skip(mut self, n: u128) -> Self {
+        self.skip = n;
+        self
+    }
+
+    /**
+     * Discards the first `2^m` points of the sequence (see `skip`). Skipping a power of two
+     * preserves the balance of the points which follow, so this is the recommended way of
+     * discarding initial points.
+     **/
+    pub fn skip_pow2(mut self, m: u32) -> Self {
+        self.skip = 1u128.checked_shl(m).unwrap_or(u128::MAX);
+        self
+    }
+
+    /**
+     * Excludes the first point of the sequence (i.e. the origin, for unscrambled sequences) should
+     * the sequence otherwise begin there. This is necessary for transforms such as the inverse
+     * CDF which are undefined at zero.
+     **/
+    pub fn skip_zero(mut self) -> Self {
+        self.skip_zero = true;
+        self
+    }
+
+    /**
+     * Sets the scrambling scheme (and so the seed) used to randomize the sequence
+     **/
+    pub fn scrambling(mut self, scrambling: Scrambling) -> Self {
+        self.scrambling = scrambling;
+        self
+    }
+
+    /**
+     * Validates the configuration and constructs the sequence
+     **/
+    pub fn build(self) -> Result<Sobol<T>, SobolError>
+        where T::IT: LossyFrom<P>, u128: LossyFrom<P> {
+
+        let max_dims = self.params.max_dims();
+        let res = check_config(self.dim_indices.len(), max_dims, self.resolution, T::MAX_RESOLUTION)?;
+        let internal_res = check_internal_resolution(self.internal_resolution, res, T::MAX_RESOLUTION)?;
+        for (i, dim) in self.dim_indices.iter().enumerate() {
+            if *dim >= max_dims || self.dim_indices[.. i].contains(dim) {
+                return Err(SobolError::InvalidDimension { dim: *dim, max_dims });
+            }
+        }
+
+        let bounds = self.bounds?;
+        if !bounds.is_empty() && bounds.len() != self.dim_indices.len() {
+            return Err(SobolError::BoundsMismatch { bounds: bounds.len(), dims: self.dim_indices.len() });
+        }
+
+        /* Direction values beyond the width of the type are computed in 128 bits and truncated */
+        let params = self.params;
+        let dir_vals = self.dim_indices.iter().map(|dim| {
+            if internal_res <= T::IT::BITS {
+                init_dim_direction_vals::<T::IT, P>(dim + 1, internal_res, params)
+            } else {
+                init_dim_direction_vals::<u128, P>(dim + 1, internal_res, params).into_iter()
+                    .map(|v| <T::IT as NumCast>::from(v >> (u128::BITS as usize - T::IT::BITS)).unwrap())
+                    .collect()
+            }
+        }).collect();
+
+        let mut seq = Sobol::init(dir_vals, res, internal_res);
+        seq.bounds = bounds;
+        seq.interval = self.interval;
+        if self.scrambling != Scrambling::None {
+            seq = seq.scrambled(self.scrambling);
+        }
+        let start = self.start.saturating_add(self.skip);
+        seq.skip_to(if self.skip_zero { start.max(1) } else { start });
+        Ok(seq)
+    }
+}
+
+impl<'a, T: SobolType + Float, P> SobolBuilder<'a, T, P> {
+
+    /**
+     * Sets the interval onto which values are rendered (see `Interval`). Endpoints are guaranteed
+     * for values in the unit interval, before any output bounds are applied.
+     **/
+    pub fn interval(mut self, interval: Interval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /**
+     * Maps the values of each dimension from the unit interval onto the interval between the
+     * corresponding `(lower, upper)` bounds. Bounds must be given for every dimension.
+     **/
+    pub fn bounds(mut self, bounds: &[(T, T)]) -> Self {
+        self.bounds = match bounds.iter().position(|(lo, hi)| !(lo.is_finite() && hi.is_finite() && lo < hi)) {
+            Some(dim) => Err(SobolError::InvalidBounds { dim }),
+            None => Ok(bounds.to_vec())
+        };
+        self
+    }
+}
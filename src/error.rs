@@ -0,0 +1,90 @@
+use std::error::Error;
+use std::fmt;
+
+
+/**
+ * Errors arising from an invalid sequence configuration
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SobolError {
+
+    /** The sequence was configured with more dimensions than its parameters support */
+    TooManyDimensions { dims: usize, max_dims: usize },
+
+    /** The sequence was configured with no dimensions */
+    ZeroDimensions,
+
+    /** The requested resolution is zero or exceeds that supported by the sequence type */
+    UnsupportedResolution { resolution: usize, max_resolution: usize },
+
+    /** The requested internal resolution is less than the resolution or exceeds the maximum */
+    UnsupportedInternalResolution { internal_resolution: usize, resolution: usize, max_internal_resolution: usize },
+
+    /** A selected (zero-based) dimension is beyond those supported by the parameters or is repeated */
+    InvalidDimension { dim: usize, max_dims: usize },
+
+    /** The output bounds of a (zero-based) dimension are not finite or are empty */
+    InvalidBounds { dim: usize },
+
+    /** The number of output bounds differs from the number of dimensions */
+    BoundsMismatch { bounds: usize, dims: usize }
+}
+
+impl fmt::Display for SobolError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SobolError::TooManyDimensions { dims, max_dims } =>
+                write!(f, "Parameters for this Sobol sequence support values with a maximum of {} dimensions \
+                           but was configured for {}", max_dims, dims),
+            SobolError::ZeroDimensions =>
+                write!(f, "Sobol sequences must have at least one dimension"),
+            SobolError::UnsupportedResolution { resolution, max_resolution } =>
+                write!(f, "Resolution must be between 1 and {} bits for this type but was {}", max_resolution, resolution),
+            SobolError::UnsupportedInternalResolution { internal_resolution, resolution, max_internal_resolution } =>
+                write!(f, "Internal resolution must be between {} and {} bits for this sequence but was {}",
+                       resolution, max_internal_resolution, internal_resolution),
+            SobolError::InvalidDimension { dim, max_dims } =>
+                write!(f, "Dimension {} is repeated or beyond the {} dimensions supported by the parameters", dim, max_dims),
+            SobolError::InvalidBounds { dim } =>
+                write!(f, "Bounds of dimension {} must be finite with the lower bound less than the upper", dim),
+            SobolError::BoundsMismatch { bounds, dims } =>
+                write!(f, "Bounds were given for {} dimensions but the sequence has {}", bounds, dims)
+        }
+    }
+}
+
+impl Error for SobolError {}
+
+/**
+ * Validates the dimensionality and resolution of a sequence, returning the resolution to be used
+ * (i.e. the maximum when none is given)
+ */
+pub(crate) fn check_config(dims: usize, max_dims: usize, resolution: Option<usize>, max_resolution: usize) -> Result<usize, SobolError> {
+    if dims == 0 {
+        return Err(SobolError::ZeroDimensions);
+    }
+    if dims > max_dims {
+        return Err(SobolError::TooManyDimensions { dims, max_dims });
+    }
+    match resolution {
+        None => Ok(max_resolution),
+        Some(res) if res >= 1 && res <= max_resolution => Ok(res),
+        Some(res) => Err(SobolError::UnsupportedResolution { resolution: res, max_resolution })
+    }
+}
+
+/**
+ * Validates the internal resolution of a sequence of given resolution, returning the internal
+ * resolution to be used (i.e. the resolution itself when none is given). Types narrower than 64
+ * bits are limited to 63 bits such that the length of their sequences is representable as `usize`
+ * on 64-bit targets.
+ */
+pub(crate) fn check_internal_resolution(internal_resolution: Option<usize>, resolution: usize, max_resolution: usize) -> Result<usize, SobolError> {
+    let max_internal_resolution = max_resolution.max(63);
+    match internal_resolution {
+        None => Ok(resolution),
+        Some(res) if res >= resolution && res <= max_internal_resolution => Ok(res),
+        Some(res) => Err(SobolError::UnsupportedInternalResolution { internal_resolution: res, resolution, max_internal_resolution })
+    }
+}
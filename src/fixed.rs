@@ -0,0 +1,193 @@
+use crate::{Sobol, SobolType, SobolParams, SobolError, InternalType, LossyFrom, Scrambling, remaining_hint, remaining_len};
+use crate::error::check_config;
+use crate::scramble::Scrambler;
+
+use std::array;
+use std::iter::FusedIterator;
+
+use num_traits::Zero;
+
+
+/** The maximum number of direction values per dimension (i.e. the widest `InternalType`) */
+const MAX_BITS: usize = 128;
+
+/**
+ * A low-discrepancy Sobol sequence generator with a fixed number of dimensions known at compile
+ * time. Points are yielded as arrays and direction values are stored inline, so no heap
+ * allocation takes place during generation.
+ */
+#[derive(Clone)]
+pub struct SobolN<T: SobolType, const D: usize> {
+    pub resolution: usize,
+    /* Direction values by bit, such that `dir_vals[i][dim]` is the i-th value of dimension `dim` */
+    dir_vals: [[T::IT; D]; MAX_BITS],
+    state: [T::IT; D],
+    scrambler: Scrambler<T::IT>,
+    pub count: u128,
+    pub max_len: u128
+}
+
+impl<T: SobolType, const D: usize> SobolN<T, D> {
+
+    /**
+     * Constructs a new sequence
+     **/
+    pub fn new<P>(params: &dyn SobolParams<P>) -> Self
+        where T::IT: LossyFrom<P> {
+
+        Self::new_with_resolution::<P>(params, None)
+    }
+
+    /**
+     * Constructs a new sequence of given resolution (see `Sobol::new_with_resolution`)
+     **/
+    pub fn new_with_resolution<P>(params: &dyn SobolParams<P>, resolution: Option<usize>) -> Self
+        where T::IT: LossyFrom<P> {
+
+        let res = resolution
+            .filter(|res| *res <= T::MAX_RESOLUTION)
+            .unwrap_or(T::MAX_RESOLUTION);
+
+        assert!(D <= params.max_dims(), "Parameters for this Sobol sequence support values with a maximum of \
+                                          {} dimensions but was configured for {}.", params.max_dims(), D);
+
+        Self::init(params, res)
+    }
+
+    /**
+     * Constructs a new sequence, returning an error rather than panicking for an invalid number
+     * of dimensions (see `Sobol::try_new`)
+     **/
+    pub fn try_new<P>(params: &dyn SobolParams<P>) -> Result<Self, SobolError>
+        where T::IT: LossyFrom<P> {
+
+        Self::try_new_with_resolution::<P>(params, None)
+    }
+
+    /**
+     * Constructs a new sequence of given resolution, returning an error for an invalid number of
+     * dimensions or resolution (see `Sobol::try_new_with_resolution`)
+     **/
+    pub fn try_new_with_resolution<P>(params: &dyn SobolParams<P>, resolution: Option<usize>) -> Result<Self, SobolError>
+        where T::IT: LossyFrom<P> {
+
+        let res = check_config(D, params.max_dims(), resolution, T::MAX_RESOLUTION)?;
+        Ok(Self::init(params, res))
+    }
+
+    /** Constructs a new sequence given a valid configuration */
+    fn init<P>(params: &dyn SobolParams<P>, res: usize) -> Self
+        where T::IT: LossyFrom<P> {
+
+        let mut dir_vals = [[T::IT::zero(); D]; MAX_BITS];
+        for (dim, init) in Sobol::<T>::init_direction_vals::<P>(D, res, params).into_iter().enumerate() {
+            for (i, dir) in init.into_iter().enumerate() {
+                dir_vals[i][dim] = dir;
+            }
+        }
+
+        SobolN {
+            resolution: res,
+            dir_vals,
+            state: [T::IT::zero(); D],
+            scrambler: Scrambler::new(Scrambling::None, D, res, T::MAX_RESOLUTION),
+            count: 0,
+            max_len: 1u128.checked_shl(res as u32).unwrap_or(u128::MAX)
+        }
+    }
+
+    /**
+     * Randomizes the sequence according to the given scrambling scheme (see `Sobol::scrambled`)
+     **/
+    pub fn scrambled(mut self, scrambling: Scrambling) -> Self {
+        self.scrambler.rescramble_directions(scrambling, &mut self.dir_vals);
+        self.scrambler = Scrambler::new(scrambling, D, self.resolution, T::MAX_RESOLUTION);
+
+        let count = self.count;
+        self.skip_to(count);
+        self
+    }
+
+    /** The scrambling scheme applied to this sequence */
+    pub fn scrambling(&self) -> Scrambling {
+        self.scrambler.mode
+    }
+
+    /**
+     * Positions the sequence such that the next point produced is the one at the given zero-based
+     * index (see `Sobol::skip_to`)
+     **/
+    pub fn skip_to(&mut self, index: u128) {
+        let index = index.min(self.max_len);
+
+        self.state = if index > 0 {
+            self.point_vals(index - 1)
+        } else { [T::IT::zero(); D] };
+        self.count = index;
+    }
+
+    /**
+     * Returns the point at the given zero-based index without affecting the state of the
+     * sequence, or `None` if the index lies beyond its end
+     **/
+    pub fn point_at(&self, index: u128) -> Option<[T; D]> {
+        if index < self.max_len {
+            Some(self.render(&self.point_vals(index)))
+        } else { None }
+    }
+
+    /** Computes the internal values of the point at a given index */
+    fn point_vals(&self, index: u128) -> [T::IT; D] {
+        let mut vals = [T::IT::zero(); D];
+
+        let mut bits = index ^ (index >> 1);
+        while bits != 0 {
+            T::IT::xor_assign_slice(&mut vals, &self.dir_vals[bits.trailing_zeros() as usize]);
+            bits &= bits - 1;
+        }
+        vals
+    }
+
+    /** Scrambles and renders the internal values of a point */
+    #[inline] fn render(&self, vals: &[T::IT; D]) -> [T; D] {
+        array::from_fn(|dim| T::render(self.scrambler.apply(dim, vals[dim])))
+    }
+}
+
+impl<T: SobolType, const D: usize> Iterator for SobolN<T, D> {
+
+    type Item = [T; D];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.count < self.max_len {
+            if self.count > 0 {
+                let c = Sobol::<T>::rightmost_zero(self.count - 1);
+                T::IT::xor_assign_slice(&mut self.state, &self.dir_vals[c]);
+            }
+            self.count += 1;
+
+            Some(self.render(&self.state))
+        } else { None }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining_hint(self.max_len - self.count)
+    }
+
+    fn count(self) -> usize {
+        remaining_len(self.max_len - self.count)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.count < self.max_len {
+            self.point_at(self.max_len - 1)
+        } else { None }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_to(self.count.saturating_add(n as u128));
+        self.next()
+    }
+}
+
+impl<T: SobolType, const D: usize> FusedIterator for SobolN<T, D> {}
@@ -0,0 +1,24 @@
+/**
+ * The interval onto which floating point sequences are rendered. Values of resolution `res` are
+ * multiples of `2^-res` and each mode maps them without rounding, so the stated endpoints are
+ * guaranteed for `f32` and `f64` sequences at every supported resolution. Integer sequences are
+ * unaffected.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Interval {
+
+    /** Values lie in `[0,1)`, with the first point of the sequence at zero (the default) */
+    #[default]
+    ClosedOpen,
+
+    /**
+     * Values lie in `(0,1)`, offset by half the spacing between values such that they are
+     * centered in the cells of width `2^-res`. The offset requires an extra bit of precision, so
+     * at the maximum resolution of the type (24 bits for `f32`, 53 bits for `f64`) values are
+     * rendered with one bit less.
+     */
+    Open,
+
+    /** Values lie in `(0,1]`, offset by the full spacing between values */
+    OpenClosed
+}
@@ -1,16 +1,37 @@
 pub mod params;
+mod builder;
+mod error;
+mod fixed;
+mod interval;
+mod scramble;
+mod simd;
+mod table;
 mod type_support;
 
-pub use self::type_support::*;
+#[cfg(feature = "rayon")]
+mod par;
 
-use std::iter::Iterator;
+pub use self::builder::SobolBuilder;
+pub use self::error::SobolError;
+pub use self::fixed::SobolN;
+pub use self::interval::Interval;
+pub use self::scramble::Scrambling;
+pub use self::table::{SobolTable, SobolCursor};
+
+#[cfg(feature = "rayon")]
+pub use self::par::{ParSobol, SobolChunk};
+
+use self::scramble::Scrambler;
+
+use std::convert::TryFrom;
+use std::iter::{FusedIterator, Iterator};
 use std::ops::{AddAssign, BitAnd, BitXor, BitXorAssign, Mul, Shl, Shr, Sub};
 use std::str::FromStr;
 use std::fmt::Display;
 
 extern crate num_traits;
 
-use num_traits::{Bounded, One, Zero, Unsigned, PrimInt};
+use num_traits::{One, Zero, Unsigned, PrimInt, WrappingAdd};
 
 
 /**
@@ -20,10 +41,15 @@ use num_traits::{Bounded, One, Zero, Unsigned, PrimInt};
 pub struct Sobol<T: SobolType> {
     pub dims: usize,
     pub resolution: usize,
+    /* Direction values by bit, such that `dir_vals[i][dim]` is the i-th value of dimension `dim` */
     dir_vals: Vec<Vec<T::IT>>,
-    previous: Option<Vec<T::IT>>,
-    pub count: T::IT,
-    pub max_len: T::IT
+    state: Vec<T::IT>,
+    scrambler: Scrambler<T::IT>,
+    /* Per-dimension output bounds, or empty if values are left in their natural range */
+    bounds: Vec<(T, T)>,
+    interval: Interval,
+    pub count: u128,
+    pub max_len: u128
 }
 
 impl<T: SobolType> Sobol<T> {
@@ -41,7 +67,8 @@ impl<T: SobolType> Sobol<T> {
      * Constructs a new sequence of given resolution. Resolution is the number of bits used in the
      * computation of the sequence and by default is the size of the underlying type. This
      * constructor is useful for reducing the number of cycles necessary to generate each point when the
-     * length of the sequence is not expected to approach it's theorectical maximum (2^res).
+     * length of the sequence is not expected to approach it's theorectical maximum (2^res). See
+     * `SobolBuilder::internal_resolution` for sequences longer than 2^res.
      **/
     pub fn new_with_resolution<P>(dims: usize, params: &dyn SobolParams<P>, resolution: Option<usize>) -> Self
         where T::IT: LossyFrom<P> {
@@ -53,58 +80,346 @@ impl<T: SobolType> Sobol<T> {
         assert!(dims <= params.max_dims(), "Parameters for this Sobol sequence support values with a maximum of \
                                             {} dimensions but was configured for {}.", params.max_dims(), dims);
 
+        Self::init(Self::init_direction_vals::<P>(dims, res, params), res, res)
+    }
+
+    /**
+     * Constructs a new sequence, returning an error rather than panicking if the number of
+     * dimensions is zero or exceeds that supported by the parameters
+     **/
+    pub fn try_new<P>(dims: usize, params: &dyn SobolParams<P>) -> Result<Self, SobolError>
+        where T::IT: LossyFrom<P> {
+
+        Self::try_new_with_resolution::<P>(dims, params, None)
+    }
+
+    /**
+     * Constructs a new sequence of given resolution (see `new_with_resolution`). Unlike that
+     * constructor, an error is returned for an invalid number of dimensions and for a resolution
+     * which is zero or exceeds that supported by the type (rather than falling back to the maximum).
+     **/
+    pub fn try_new_with_resolution<P>(dims: usize, params: &dyn SobolParams<P>, resolution: Option<usize>) -> Result<Self, SobolError>
+        where T::IT: LossyFrom<P> {
+
+        let res = error::check_config(dims, params.max_dims(), resolution, T::MAX_RESOLUTION)?;
+        Ok(Self::init(Self::init_direction_vals::<P>(dims, res, params), res, res))
+    }
+
+    /**
+     * Constructs a new sequence from per-dimension direction values. There is one direction value
+     * per bit of the internal resolution (so the sequence has `2^internal_res` points) but values
+     * are truncated to the leading `res` bits. Sequences with an internal resolution of 128 bits
+     * stop one point short, since their length isn't representable.
+     */
+    pub(crate) fn init(dir_vals: Vec<Vec<T::IT>>, res: usize, internal_res: usize) -> Self {
+        let dims = dir_vals.len();
+        let mut dir_vals = transpose(dir_vals, internal_res);
+        let mask = scramble::leading_mask::<T::IT>(res);
+        dir_vals.iter_mut().flatten().for_each(|v| *v = *v & mask);
+
         Sobol {
             dims,
             resolution: res,
-            dir_vals: Self::init_direction_vals::<P>(dims, res, params),
-            count: T::IT::zero(),
-            max_len: T::IT::max_value() >> (T::IT::BITS - res),
-            previous: None
+            dir_vals,
+            count: 0,
+            max_len: 1u128.checked_shl(internal_res as u32).unwrap_or(u128::MAX),
+            state: vec![T::IT::zero(); dims],
+            scrambler: Scrambler::new(Scrambling::None, dims, res, T::MAX_RESOLUTION),
+            bounds: vec![],
+            interval: Interval::ClosedOpen
         } as Sobol<T>
     }
 
+    /**
+     * Randomizes the sequence according to the given scrambling scheme, replacing any scrambling
+     * previously configured. Scrambling does not affect the position of the sequence.
+     **/
+    pub fn scrambled(mut self, scrambling: Scrambling) -> Self {
+        self.scrambler.rescramble_directions(scrambling, &mut self.dir_vals);
+        self.scrambler = Scrambler::new(scrambling, self.dims, self.resolution, T::MAX_RESOLUTION);
+
+        let count = self.count;
+        self.skip_to(count);
+        self
+    }
+
+    /**
+     * Produces independent replicates of this sequence, each randomized by a digital shift. The
+     * shifts are drawn from a stream seeded by `seed` such that the replicates are reproducible.
+     * This is useful for estimating the error of a quasi-Monte Carlo integration.
+     **/
+    pub fn shifted_replicates(&self, count: usize, seed: u64) -> Vec<Self>
+        where Self: Clone {
+
+        let mut state = seed;
+        (0 .. count)
+            .map(|_| self.clone().scrambled(Scrambling::DigitalShift(scramble::split_mix(&mut state))))
+            .collect()
+    }
+
+    /** The scrambling scheme applied to this sequence */
+    pub fn scrambling(&self) -> Scrambling {
+        self.scrambler.mode
+    }
+
     /**
      * Initializes per-dimension direction values given sequence parameters
      */
     pub fn init_direction_vals<P>(dims: usize, resolution: usize, params: &dyn SobolParams<P>) -> Vec<Vec<T::IT>>
         where T::IT: LossyFrom<P> {
 
-        let bits = T::IT::BITS;
+        (1 ..= dims).map(|dim| init_dim_direction_vals(dim, resolution, params)).collect()
+    }
 
-        (1 ..= dims).map(|dim| match dim {
-            1 => (1 ..= resolution).map(|i| T::IT::one() << (bits - i)).collect(),
-            _ => {
-                /* Import the parameters needed to prepare this dimension's direction vector */
-                let p = params.get_dim(dim);
-                let s = if resolution >= p.s() { p.s() } else { resolution };
 
-                /* Shift initial directions */
-                let mut dirs: Vec<T::IT> = vec![T::IT::zero(); resolution];
-                for i in 1 ..= s {
-                    let m = T::IT::lossy_from(p.m(i - 1));
-                    dirs[i - 1] = m << (bits - i);
+    /**
+     * Positions the sequence such that the next point produced is the one at the given zero-based
+     * index. Rather than iterating, the sequence state is rebuilt directly from the binary digits
+     * of the index, so the cost depends only on the resolution and not on the distance skipped.
+     * Indices beyond the end of the sequence leave it exhausted.
+     **/
+    pub fn skip_to(&mut self, index: u128) {
+        let index = index.min(self.max_len);
+
+        let mut state = std::mem::take(&mut self.state);
+        if index > 0 {
+            self.point_vals_into(index - 1, &mut state);
+        } else {
+            state.iter_mut().for_each(|v| *v = T::IT::zero());
+        }
+        self.state = state;
+        self.count = index;
+    }
+
+    /**
+     * Writes the next point of the sequence into the given buffer, which must be exactly `dims`
+     * in length. Returns `false` (leaving the buffer untouched) if the sequence is exhausted.
+     * Unlike `next`, this performs no heap allocation.
+     **/
+    pub fn next_into(&mut self, out: &mut [T]) -> bool {
+        assert_eq!(out.len(), self.dims, "Buffer must have length equal to the number of dimensions");
+
+        let advanced = self.advance();
+        if advanced {
+            self.render_into(&self.state, out);
+        }
+        advanced
+    }
+
+    /**
+     * Writes up to `n_points` successive points into the given buffer in row-major order (i.e.
+     * all dimensions of the first point, then all dimensions of the second, and so on). Returns
+     * the number of points written, which is less than `n_points` only if the sequence is
+     * exhausted. This performs no heap allocation and is the fastest way to generate points.
+     **/
+    pub fn fill(&mut self, out: &mut [T], n_points: usize) -> usize {
+        assert!(out.len() >= n_points * self.dims, "Buffer is too small to hold {} points", n_points);
+
+        let mut written = 0;
+        for point in out.chunks_exact_mut(self.dims).take(n_points) {
+            if !self.advance() { break; }
+            self.render_into(&self.state, point);
+            written += 1;
+        }
+        written
+    }
+
+    /**
+     * Writes up to `n_points` successive points into the given buffer in column-major order (i.e.
+     * the first dimension of all points, then the second dimension of all points, and so on) such
+     * that the column of dimension `d` begins at offset `d * n_points`. Returns the number of
+     * points written, which is less than `n_points` only if the sequence is exhausted.
+     *
+     * Each column is produced directly rather than by transposing points, and no heap allocation
+     * takes place. Values are computed in aligned runs of 64 points, each being the value of the
+     * first point of its run XORed with a combination of the dimension's first 6 direction values,
+     * so the values of a run don't depend on one another and are rendered as a slice.
+     **/
+    pub fn fill_columns(&mut self, out: &mut [T], n_points: usize) -> usize {
+        assert!(out.len() >= n_points * self.dims, "Buffer is too small to hold {} points", n_points);
+
+        let len = self.remaining_up_to(n_points);
+        if len == 0 {
+            return 0;
+        }
+
+        /* Runs of (up to) 64 points are aligned to their length, which is limited by the resolution */
+        let run = 1 << self.dir_vals.len().min(6);
+        let first = (self.count % run as u128) as usize;
+        let plain = self.is_plain();
+
+        let mut state = std::mem::take(&mut self.state);
+        for (dim, val) in state.iter_mut().enumerate() {
+            /*
+             * Within a run, `gray(b + 8m + i) = gray(b) ^ gray(8m) ^ gray(i)`, so each value is
+             * that of the first point of the run XORed with one of 8 values for each half of the
+             * index. `gray(8m)` is `8 gray(m)` with bit 2 set for odd `m`. Direction values beyond
+             * the resolution are taken to be zero, since only the first `run` values are used.
+             */
+            let dir = |k: usize| self.dir_vals.get(k).map_or(T::IT::zero(), |dirs| dirs[dim]);
+            let low = gray_values([dir(0), dir(1), dir(2)]);
+            let mut high = gray_values([dir(3), dir(4), dir(5)]);
+            high.iter_mut().skip(1).step_by(2).for_each(|h| *h ^= dir(2));
+
+            /* The value of the first point of the run containing the next point */
+            let mut base = if first > 0 {
+                *val ^ high[(first - 1) / 8] ^ low[(first - 1) % 8]
+            } else if self.count > 0 {
+                *val ^ self.dir_vals[Self::rightmost_zero(self.count - 1)][dim]
+            } else { T::IT::zero() };
+
+            let mut written = 0;
+            let mut offset = first;
+            loop {
+                let mut vals = [T::IT::zero(); 64];
+                for (chunk, h) in vals.chunks_exact_mut(8).zip(&high) {
+                    for (v, l) in chunk.iter_mut().zip(&low) {
+                        *v = base ^ *h ^ *l;
+                    }
                 }
-    
-                /* Compute remaining directions */
-                for i in s + 1 ..= resolution {
-                    dirs[i - 1] = dirs[i - s - 1] ^ (dirs[i - s - 1] >> s);
-    
-                    for k in 1 .. s {
-                        let a = T::IT::lossy_from(p.coefficient(s - k - 1));
-                        let dir = dirs[i - k - 1];
-                        dirs[i - 1] ^= a * dir;
+
+                let points = (run - offset).min(len - written);
+                let vals = &vals[offset .. offset + points];
+                let column = &mut out[dim * n_points + written ..][.. points];
+                if plain {
+                    T::render_slice(vals, column);
+                } else {
+                    for (v, o) in vals.iter().zip(column.iter_mut()) {
+                        *o = self.output(dim, *v);
                     }
                 }
 
-                dirs
+                written += points;
+                if written == len {
+                    *val = vals[points - 1];
+                    break;
+                }
+                let last = self.count + written as u128 - 1;
+                base = vals[points - 1] ^ self.dir_vals[Self::rightmost_zero(last)][dim];
+                offset = 0;
             }
-        }).collect()
+        }
+        self.state = state;
+
+        self.count += len as u128;
+        len
     }
 
+    /**
+     * Generates up to `n_points` successive points as a structure of arrays, with one `Vec` of
+     * values per dimension. See `fill_columns` for an allocation-free alternative.
+     **/
+    pub fn next_columns(&mut self, n_points: usize) -> Vec<Vec<T>>
+        where T: Default + Clone {
+
+        let mut buf = vec![T::default(); n_points * self.dims];
+        let len = self.fill_columns(&mut buf, n_points);
+        (0 .. self.dims)
+            .map(|dim| buf[dim * n_points .. dim * n_points + len].to_vec())
+            .collect()
+    }
+
+    /**
+     * Updates the internal state to that of the next point using the Gray code optimization,
+     * returning `false` if the sequence is exhausted.
+     */
+    #[inline] fn advance(&mut self) -> bool {
+        if self.count >= self.max_len {
+            return false;
+        }
+
+        let count = self.count;
+        let mut state = std::mem::take(&mut self.state);
+        self.step(&mut state, count);
+        self.state = state;
+
+        self.count += 1;
+        true
+    }
+
+    /**
+     * Transitions the given state from the point preceding `index` to the point at `index` using
+     * the Gray code recurrence. The state of the first point is all zeros, so nothing is done for
+     * an index of zero.
+     */
+    #[inline] fn step(&self, state: &mut [T::IT], index: u128) {
+        if index > 0 {
+            let c = Self::rightmost_zero(index - 1);
+            T::IT::xor_assign_slice(state, &self.dir_vals[c]);
+        }
+    }
+
+    /**
+     * Returns the point at the given zero-based index without affecting the state of the
+     * sequence, or `None` if the index lies beyond its end. Since only a shared reference is
+     * needed, a single sequence may be used to evaluate arbitrary points from many threads.
+     **/
+    pub fn point_at(&self, index: u128) -> Option<Vec<T>> {
+        if index < self.max_len {
+            let mut vals = vec![T::IT::zero(); self.dims];
+            self.point_vals_into(index, &mut vals);
+            Some(self.render(&vals))
+        } else { None }
+    }
+
+    /**
+     * Computes the internal values of the point at a given index. Each component is the XOR of
+     * the direction values selected by the set bits of the index's Gray code.
+     */
+    fn point_vals_into(&self, index: u128, out: &mut [T::IT]) {
+        out.iter_mut().for_each(|v| *v = T::IT::zero());
+
+        let mut bits = index ^ (index >> 1);
+        while bits != 0 {
+            T::IT::xor_assign_slice(out, &self.dir_vals[bits.trailing_zeros() as usize]);
+            bits &= bits - 1;
+        }
+    }
+
+    /** Scrambles and renders the internal values of a point */
+    fn render(&self, vals: &[T::IT]) -> Vec<T> {
+        vals.iter()
+            .enumerate()
+            .map(|(dim, v)| self.output(dim, *v))
+            .collect()
+    }
+
+    /** Scrambles and renders the internal values of a point into the given buffer */
+    #[inline] fn render_into(&self, vals: &[T::IT], out: &mut [T]) {
+        if self.is_plain() {
+            T::render_slice(vals, out);
+        } else {
+            for (dim, (v, o)) in vals.iter().zip(out.iter_mut()).enumerate() {
+                *o = self.output(dim, *v);
+            }
+        }
+    }
+
+    /** Whether values are rendered without scrambling, rescaling or an interval other than the default */
+    #[inline] fn is_plain(&self) -> bool {
+        self.scrambler.mode == Scrambling::None && self.bounds.is_empty() && self.interval == Interval::ClosedOpen
+    }
+
+    /** Scrambles, renders and rescales a single internal value of the given dimension */
+    #[inline] fn output(&self, dim: usize, val: T::IT) -> T {
+        let val = T::render_interval(self.scrambler.apply(dim, val), self.scrambler.output_resolution(), self.interval);
+        match self.bounds.get(dim) {
+            Some((lower, upper)) => val.rescale(lower, upper),
+            None => val
+        }
+    }
 
     /** Returns zero-based index of the rightmost binary zero. Used for the Gray code optimization */
-    #[inline] pub fn rightmost_zero(n: T::IT) -> usize {
-        (n ^ T::IT::max_value()).trailing_zeros() as usize
+    #[inline] pub fn rightmost_zero(n: u128) -> usize {
+        n.trailing_ones() as usize
+    }
+
+    /** The number of points remaining in the sequence, up to a maximum of `n_points` */
+    fn remaining_up_to(&self, n_points: usize) -> usize {
+        match usize::try_from(self.max_len - self.count) {
+            Ok(remaining) if remaining < n_points => remaining,
+            _ => n_points
+        }
     }
 }
 
@@ -113,29 +428,44 @@ impl<T: SobolType> Iterator for Sobol<T> {
     type Item = Vec<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.count < self.max_len {
-            let next = match &self.previous {
-                None => vec![T::IT::zero(); self.dims],
-                Some(previous) => {
-                    let a = self.count - T::IT::one();
-                    let c = Self::rightmost_zero(a);
-                    self.dir_vals.iter()
-                        .enumerate()
-                        .map(|(dim, dirs)| previous[dim] ^ dirs[c as usize])
-                        .collect::<Vec<T::IT>>()
-                }
-            };
+        if self.advance() {
+            Some(self.render(&self.state))
+        } else { None }
+    }
 
-            let next_render: Vec<T> = next.iter()
-                .map(|v| T::render(*v))
-                .collect();
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining_hint(self.max_len - self.count)
+    }
 
-            self.count += T::IT::one();
-            self.previous = Some(next);
+    fn count(self) -> usize {
+        remaining_len(self.max_len - self.count)
+    }
 
-            Some(next_render)
+    fn last(self) -> Option<Self::Item> {
+        if self.count < self.max_len {
+            self.point_at(self.max_len - 1)
         } else { None }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_to(self.count.saturating_add(n as u128));
+        self.next()
+    }
+}
+
+impl<T: SobolType> FusedIterator for Sobol<T> {}
+
+/** The bounds on the number of points remaining in a sequence, as given by `size_hint` */
+#[inline] pub(crate) fn remaining_hint(remaining: u128) -> (usize, Option<usize>) {
+    match usize::try_from(remaining) {
+        Ok(len) => (len, Some(len)),
+        Err(_) => (usize::MAX, None)
+    }
+}
+
+/** The number of points remaining in a sequence, which must be representable as `usize` */
+#[inline] pub(crate) fn remaining_len(remaining: u128) -> usize {
+    usize::try_from(remaining).expect("Number of remaining points exceeds usize::MAX")
 }
 
 /**
@@ -161,6 +491,32 @@ pub trait SobolType: Sized + Display {
      * in some cases (e.g. floats are limited by the size of their significand).
      */
     const MAX_RESOLUTION: usize = Self::IT::BITS;
+
+    /**
+     * Renders a slice of internal values into the given buffer. Types may override this with a
+     * vectorized implementation.
+     */
+    fn render_slice(vals: &[Self::IT], out: &mut [Self]) {
+        for (v, o) in vals.iter().zip(out.iter_mut()) {
+            *o = Self::render(*v);
+        }
+    }
+
+    /**
+     * Renders an internal value of the given resolution onto an interval. Only floating point
+     * types support intervals, so by default this is the same as `render`.
+     */
+    fn render_interval(val: Self::IT, _resolution: usize, _interval: Interval) -> Self {
+        Self::render(val)
+    }
+
+    /**
+     * Maps a rendered value from the unit interval onto the given bounds. Only floating point
+     * types support output bounds, so by default values are left unchanged.
+     */
+    fn rescale(self, _lower: &Self, _upper: &Self) -> Self {
+        self
+    }
 }
 
 /**
@@ -179,6 +535,7 @@ pub trait InternalType:
     Shr<usize, Output = Self> +
     BitAnd<Output = Self> +
     BitXor<Output = Self> +
+    WrappingAdd +
     Copy +
     PartialEq +
     PartialOrd +
@@ -186,6 +543,16 @@ pub trait InternalType:
     Display {
 
     const BITS: usize;
+
+    /**
+     * XORs each value of `src` into the corresponding value of `dst`. This is the inner loop of
+     * sequence generation and types may override it with a vectorized implementation.
+     */
+    #[inline] fn xor_assign_slice(dst: &mut [Self], src: &[Self]) {
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d ^= *s;
+        }
+    }
 }
 
 /**
@@ -198,6 +565,16 @@ pub trait SobolParams<P> {
 
     /** Maximum number of dimensions supported by this instance */
     fn max_dims(&self) -> usize;
+
+    /**
+     * Checks that the polynomial of each dimension is primitive over GF(2) and not repeated, and
+     * that there are `s` initial direction values `m_i`, each odd and less than `2^i`, reporting
+     * every violation found. Parameters are otherwise used as given, so this is worth doing for
+     * parameters which have been hand-edited or come from an untrusted source.
+     */
+    fn validate(&self) -> Result<(), Vec<params::Violation>> where u128: LossyFrom<P> {
+        params::validate(self)
+    }
 }
 
 /**
@@ -216,6 +593,15 @@ pub trait ParamDimension<P> {
 
     /** The initial direction value for bit `i`, the zero-based index from the right */
     fn m(&self, i: usize) -> P;
+
+    /**
+     * The number of initial direction values available, which should equal the degree `s`. By
+     * default it is assumed to, but implementations which may hold a different number should
+     * override this so that `SobolParams::validate` can report the discrepancy.
+     */
+    fn m_len(&self) -> usize {
+        self.s()
+    }
 }
 
 /**
@@ -226,3 +612,63 @@ pub trait ParamDimension<P> {
 pub trait LossyFrom<T>: Sized {
     fn lossy_from(_: T) -> Self;
 }
+
+/**
+ * Initializes the direction values of a single (one-based) dimension given sequence parameters.
+ * The resolution may not exceed the number of bits of the internal type `I`.
+ */
+pub(crate) fn init_dim_direction_vals<I, P>(dim: usize, resolution: usize, params: &dyn SobolParams<P>) -> Vec<I>
+    where I: InternalType + LossyFrom<P> {
+
+    let bits = I::BITS;
+
+    match dim {
+        1 => (1 ..= resolution).map(|i| I::one() << (bits - i)).collect(),
+        _ => {
+            /* Import the parameters needed to prepare this dimension's direction vector */
+            let p = params.get_dim(dim);
+            let s = if resolution >= p.s() { p.s() } else { resolution };
+
+            /* Shift initial directions */
+            let mut dirs: Vec<I> = vec![I::zero(); resolution];
+            for i in 1 ..= s {
+                let m = I::lossy_from(p.m(i - 1));
+                dirs[i - 1] = m << (bits - i);
+            }
+
+            /* Compute remaining directions */
+            for i in s + 1 ..= resolution {
+                dirs[i - 1] = dirs[i - s - 1] ^ (dirs[i - s - 1] >> s);
+
+                for k in 1 .. s {
+                    let a = I::lossy_from(p.coefficient(s - k - 1));
+                    let dir = dirs[i - k - 1];
+                    dirs[i - 1] ^= a * dir;
+                }
+            }
+
+            dirs
+        }
+    }
+}
+
+/**
+ * Computes the values of the first 8 points of a single dimension from its first 3 direction
+ * values, by reflecting the Gray code one bit at a time
+ */
+fn gray_values<I: InternalType>(dirs: [I; 3]) -> [I; 8] {
+    let mut vals = [I::zero(); 8];
+    for (k, dir) in dirs.iter().enumerate() {
+        for j in 0 .. 1 << k {
+            vals[(1 << k) + j] = vals[(1 << k) - 1 - j] ^ *dir;
+        }
+    }
+    vals
+}
+
+/** Converts per-dimension direction values to per-bit rows of direction values */
+fn transpose<I: Copy>(dir_vals: Vec<Vec<I>>, resolution: usize) -> Vec<Vec<I>> {
+    (0 .. resolution)
+        .map(|i| dir_vals.iter().map(|dirs| dirs[i]).collect())
+        .collect()
+}
@@ -0,0 +1,160 @@
+extern crate rayon;
+
+use crate::{Sobol, SobolType};
+
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+
+use num_traits::Zero;
+
+use std::convert::TryFrom;
+
+
+/**
+ * An indexed parallel iterator over the remaining points of a `Sobol` sequence. The index range is
+ * split into chunks, each of which jumps directly to its first index and then proceeds using the
+ * Gray code recurrence. Since parallel iterators must have a length representable as `usize`, at
+ * most `usize::MAX` points are visited.
+ */
+pub struct ParSobol<'a, T: SobolType> {
+    seq: &'a Sobol<T>,
+    start: u128,
+    len: usize
+}
+
+impl<T: SobolType> Sobol<T> {
+
+    /**
+     * Returns a parallel iterator over the remaining points of this sequence. The position of
+     * the sequence itself is unaffected.
+     **/
+    pub fn par_iter(&self) -> ParSobol<'_, T> {
+        let len = usize::try_from(self.max_len - self.count).unwrap_or(usize::MAX);
+        ParSobol { seq: self, start: self.count, len }
+    }
+}
+
+impl<'a, T> IntoParallelIterator for &'a Sobol<T>
+    where T: SobolType + Send + Sync, T::IT: Send + Sync {
+
+    type Iter = ParSobol<'a, T>;
+    type Item = Vec<T>;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.par_iter()
+    }
+}
+
+impl<'a, T> ParallelIterator for ParSobol<'a, T>
+    where T: SobolType + Send + Sync, T::IT: Send + Sync {
+
+    type Item = Vec<T>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item> {
+
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len)
+    }
+}
+
+impl<'a, T> IndexedParallelIterator for ParSobol<'a, T>
+    where T: SobolType + Send + Sync, T::IT: Send + Sync {
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        callback.callback(SobolProducer { seq: self.seq, start: self.start, len: self.len })
+    }
+}
+
+/** Produces the points of a contiguous chunk of the index range */
+struct SobolProducer<'a, T: SobolType> {
+    seq: &'a Sobol<T>,
+    start: u128,
+    len: usize
+}
+
+impl<'a, T> Producer for SobolProducer<'a, T>
+    where T: SobolType + Send + Sync, T::IT: Send + Sync {
+
+    type Item = Vec<T>;
+    type IntoIter = SobolChunk<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.start + self.len as u128;
+        SobolChunk { seq: self.seq, state: None, front: self.start, back: end }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mid = self.start + index as u128;
+        (SobolProducer { seq: self.seq, start: self.start, len: index },
+         SobolProducer { seq: self.seq, start: mid, len: self.len - index })
+    }
+}
+
+/**
+ * A sequential iterator over a chunk of a sequence. Points are generated from the front using the
+ * Gray code recurrence (seeded by a direct jump on first use) while points taken from the back
+ * are computed directly.
+ */
+pub struct SobolChunk<'a, T: SobolType> {
+    seq: &'a Sobol<T>,
+    state: Option<Vec<T::IT>>,
+    front: u128,
+    back: u128
+}
+
+impl<'a, T: SobolType> Iterator for SobolChunk<'a, T> {
+
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let seq = self.seq;
+        let front = self.front;
+        let state = self.state.get_or_insert_with(|| {
+            let mut state = vec![T::IT::zero(); seq.dims];
+            if front > 0 {
+                seq.point_vals_into(front - 1, &mut state);
+            }
+            state
+        });
+
+        seq.step(state, front);
+
+        self.front += 1;
+        Some(seq.render(state))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: SobolType> DoubleEndedIterator for SobolChunk<'a, T> {
+
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.seq.point_at(self.back)
+    }
+}
+
+impl<'a, T: SobolType> ExactSizeIterator for SobolChunk<'a, T> {}
@@ -0,0 +1,72 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+
+/**
+ * Errors arising from loading parameter values
+ */
+#[derive(Debug)]
+pub enum ParamsError {
+
+    /** The parameter values could not be read */
+    Io(io::Error),
+
+    /** The parameter values appear to be gzip compressed but could not be decompressed */
+    Decompression(io::Error),
+
+    /**
+     * A line of the parameter values is malformed. Lines and columns are one-based, and the token
+     * is that at fault (or empty if one was missing at the end of the line).
+     */
+    Format { line: usize, column: usize, token: String, message: String },
+
+    /** Packed parameter values are malformed at the given byte offset */
+    Packed { offset: usize, message: String }
+}
+
+impl ParamsError {
+
+    /** Reports a malformed line of parameter values */
+    pub(crate) fn format<M: Into<String>>(line: usize, column: usize, token: &str, message: M) -> Self {
+        ParamsError::Format { line, column, token: token.to_string(), message: message.into() }
+    }
+
+    /** Reports malformed packed parameter values */
+    pub(crate) fn packed<M: Into<String>>(offset: usize, message: M) -> Self {
+        ParamsError::Packed { offset, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParamsError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Io(err) => write!(f, "Unable to read parameters: {}", err),
+            ParamsError::Decompression(err) => write!(f, "Unable to decompress parameters: {}", err),
+            ParamsError::Format { line, column, token, message } if token.is_empty() =>
+                write!(f, "Invalid parameters at line {}, column {}: {}", line, column, message),
+            ParamsError::Format { line, column, token, message } =>
+                write!(f, "Invalid parameters at line {}, column {} ('{}'): {}", line, column, token, message),
+            ParamsError::Packed { offset, message } =>
+                write!(f, "Invalid packed parameters at byte {}: {}", offset, message)
+        }
+    }
+}
+
+impl Error for ParamsError {
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParamsError::Io(err) | ParamsError::Decompression(err) => Some(err),
+            ParamsError::Format { .. } | ParamsError::Packed { .. } => None
+        }
+    }
+}
+
+impl From<io::Error> for ParamsError {
+
+    fn from(err: io::Error) -> Self {
+        ParamsError::Io(err)
+    }
+}
@@ -1,11 +1,23 @@
+#[cfg(feature = "gzip")]
 extern crate libflate;
 
 use crate::{SobolParams, ParamDimension};
-
-use std::io::{BufRead, BufReader, Cursor};
+use super::ParamsError;
+#[cfg(any(feature = "params-minimal", feature = "params-standard", feature = "params-extended"))]
+use super::PackedParams;
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+#[cfg(feature = "gzip")]
 use libflate::gzip::Decoder;
 
 
+/** The leading bytes of gzip compressed data */
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+
 pub struct JoeKuoD6 {
     pub dim_params: Vec<JoeKuoD6Dim>,
     pub max_dims: usize
@@ -14,37 +26,110 @@ pub struct JoeKuoD6 {
 impl JoeKuoD6 {
 
     /**
-     * Load parameter values supporting up to 1000 dimensions
+     * Load parameter values supporting up to 1000 dimensions. Every dimension is decoded up front,
+     * so unless the values are to be inspected or modified, `PackedParams::standard` is cheaper.
      */
+    #[cfg(feature = "params-standard")]
     pub fn standard() -> Self {
-        JoeKuoD6::load_gz_bytes(include_bytes!("data/new-joe-kuo-6.1000.gz"))
+        PackedParams::standard().unpack()
     }
 
     /**
-     * Load parameter values supporting up to 100 dimensions
+     * Load parameter values supporting up to 100 dimensions (see `standard`)
      */
+    #[cfg(feature = "params-minimal")]
     pub fn minimal() -> Self {
-        JoeKuoD6::load_gz_bytes(include_bytes!("data/new-joe-kuo-6.100.gz"))
+        PackedParams::minimal().unpack()
     }
 
     /**
-     * Load parameter values supporting up to 21,201 dimensions
+     * Load parameter values supporting up to 21,201 dimensions (see `standard`)
      */
+    #[cfg(feature = "params-extended")]
     pub fn extended() -> Self {
-        JoeKuoD6::load_gz_bytes(include_bytes!("data/new-joe-kuo-6.21201.gz"))
-    }
-
-    /** Instantiates parameter struct from gz sequence of bytes */
-    fn load_gz_bytes(bytes: &[u8]) -> JoeKuoD6 {
-        let mut byte_cursor = Cursor::new(bytes);
-        let gz_decoder = Decoder::new(&mut byte_cursor).unwrap();
-        let dim_params: Vec<JoeKuoD6Dim> = BufReader::new(gz_decoder)
-            .lines()
-            .skip(1)
-            .map(|l| JoeKuoD6Dim::parse(&l.unwrap()))
-            .collect();
+        PackedParams::extended().unpack()
+    }
+
+    /**
+     * Loads parameter values in the text format of Joe and Kuo (i.e. a header line followed by
+     * one line of `d s a m_i...` per dimension, beginning with the second) from a reader. Gzip
+     * compressed input is detected and decompressed transparently, provided the `gzip` feature is
+     * enabled (and is otherwise reported as a decompression error).
+     */
+    pub fn from_reader<R: Read>(reader: R) -> Result<Self, ParamsError> {
+        let mut reader = BufReader::new(reader);
+        if reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            JoeKuoD6::parse_gz(reader)
+        } else {
+            JoeKuoD6::parse_lines(reader, false)
+        }
+    }
+
+    /**
+     * Loads parameter values from a file in the text format of Joe and Kuo, which may be gzip
+     * compressed (see `from_reader`)
+     */
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ParamsError> {
+        JoeKuoD6::from_reader(File::open(path)?)
+    }
+
+    /** Parses parameters from gzip compressed text */
+    #[cfg(feature = "gzip")]
+    fn parse_gz<R: Read>(reader: R) -> Result<Self, ParamsError> {
+        let decoder = Decoder::new(reader).map_err(ParamsError::Decompression)?;
+        JoeKuoD6::parse_lines(BufReader::new(decoder), true)
+    }
+
+    /** Reports that compressed parameters can't be parsed without the `gzip` feature */
+    #[cfg(not(feature = "gzip"))]
+    fn parse_gz<R: Read>(_reader: R) -> Result<Self, ParamsError> {
+        Err(ParamsError::Decompression(io::Error::new(io::ErrorKind::Unsupported,
+            "gzip compressed parameters require the `gzip` feature")))
+    }
+
+    /**
+     * Parses parameters from lines of text. Blank lines are ignored, as is a leading header line
+     * (i.e. one which doesn't begin with a number). Read errors of compressed input are reported
+     * as decompression errors, since the gzip decoder reports corrupt data as such.
+     */
+    fn parse_lines<B: BufRead>(mut reader: B, compressed: bool) -> Result<Self, ParamsError> {
+        let mut dim_params = vec![];
+        let mut buf = vec![];
+        for line_no in 1 .. {
+            buf.clear();
+            let read = reader.read_until(b'\n', &mut buf).map_err(|err| match err.kind() {
+                io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof if compressed => ParamsError::Decompression(err),
+                _ => ParamsError::Io(err)
+            })?;
+            if read == 0 { break; }
+
+            let line = std::str::from_utf8(&buf).map_err(|err| {
+                let column = String::from_utf8_lossy(&buf[.. err.valid_up_to()]).chars().count() + 1;
+                ParamsError::format(line_no, column, "", "line is not valid UTF-8")
+            })?;
+            let first = match line.split_whitespace().next() {
+                Some(first) => first,
+                None => continue
+            };
+            if dim_params.is_empty() && line_no == 1 && first.parse::<u16>().is_err() {
+                continue;
+            }
+
+            dim_params.push(JoeKuoD6Dim::parse_line(line, line_no)?);
+        }
+
         let max_dims = dim_params.len() + 1;
-        JoeKuoD6 { dim_params, max_dims }
+        Ok(JoeKuoD6 { dim_params, max_dims })
+    }
+}
+
+impl FromStr for JoeKuoD6 {
+
+    type Err = ParamsError;
+
+    /** Parses parameter values in the text format of Joe and Kuo (see `from_reader`) */
+    fn from_str(s: &str) -> Result<Self, ParamsError> {
+        JoeKuoD6::parse_lines(s.as_bytes(), false)
     }
 }
 
@@ -71,13 +156,59 @@ impl JoeKuoD6Dim {
 
     /** Parses the dimensional parameters from string according to the format provided by Joe/Kuo */
     pub fn parse(s: &str) -> Self {
-        let mut tokens = s.split_whitespace();
-        let d = tokens.next().unwrap().parse::<u16>().unwrap();
-        tokens.next();
-        let a = tokens.next().unwrap().parse::<u32>().unwrap();
-        let m = tokens.map(|t| t.parse::<u32>().unwrap()).collect();
-        JoeKuoD6Dim { d, a, m }
+        JoeKuoD6Dim::try_parse(s).unwrap_or_else(|err| panic!("{}", err))
     }
+
+    /**
+     * Parses the dimensional parameters from string, reporting the column and token of the first
+     * malformed value (on line 1), or the missing or surplus values if the number of initial
+     * direction values differs from the degree `s`
+     */
+    pub fn try_parse(s: &str) -> Result<Self, ParamsError> {
+        JoeKuoD6Dim::parse_line(s, 1)
+    }
+
+    /** Parses the dimensional parameters from the given line of a parameter file */
+    fn parse_line(line: &str, line_no: usize) -> Result<Self, ParamsError> {
+        let end = line.trim_end().chars().count() + 1;
+        let mut tokens = columns(line);
+        let mut next = |name: &str| tokens.next()
+            .ok_or_else(|| ParamsError::format(line_no, end, "", format!("missing {}", name)));
+
+        let (column, token) = next("dimension d")?;
+        let d = parse_token::<u16>(token, line_no, column, "dimension d")?;
+        let (column, token) = next("degree s")?;
+        let s = parse_token::<usize>(token, line_no, column, "degree s")?;
+        let (column, token) = next("coefficients a")?;
+        let a = parse_token::<u32>(token, line_no, column, "coefficients a")?;
+
+        let mut m = Vec::with_capacity(s.min(32));
+        for (column, token) in tokens {
+            if m.len() == s {
+                return Err(ParamsError::format(line_no, column, token,
+                    format!("expected {} initial direction values for degree {}", s, s)));
+            }
+            m.push(parse_token::<u32>(token, line_no, column, "initial direction value m_i")?);
+        }
+        if m.len() < s {
+            return Err(ParamsError::format(line_no, end, "",
+                format!("expected {} initial direction values for degree {} but found {}", s, s, m.len())));
+        }
+        Ok(JoeKuoD6Dim { d, a, m })
+    }
+}
+
+/** Splits a line into whitespace separated tokens, paired with their one-based (character) columns */
+fn columns(line: &str) -> impl Iterator<Item=(usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (line[.. offset].chars().count() + 1, token)
+    })
+}
+
+/** Parses a single value, reporting its position and what was expected should it be malformed */
+fn parse_token<V: FromStr>(token: &str, line: usize, column: usize, name: &str) -> Result<V, ParamsError> {
+    token.parse::<V>().map_err(|_| ParamsError::format(line, column, token, format!("invalid {}", name)))
 }
 
 impl ParamDimension<u32> for JoeKuoD6Dim {
@@ -101,4 +232,9 @@ impl ParamDimension<u32> for JoeKuoD6Dim {
     fn m(&self, i: usize) -> u32 {
         self.m[i]
     }
+
+    #[inline]
+    fn m_len(&self) -> usize {
+        self.m.len()
+    }
 }
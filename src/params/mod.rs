@@ -1,3 +1,11 @@
+mod error;
 mod joe_kuo_d6;
+mod packed;
+mod validate;
 
+pub use self::error::ParamsError;
 pub use self::joe_kuo_d6::*;
+pub use self::packed::PackedParams;
+pub use self::validate::{Violation, ViolationKind, MAX_VALIDATED_DEGREE};
+
+pub(crate) use self::validate::validate;
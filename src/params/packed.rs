@@ -0,0 +1,175 @@
+use crate::{SobolParams, ParamDimension};
+use super::{JoeKuoD6, JoeKuoD6Dim, ParamsError};
+
+use std::convert::TryInto;
+
+
+/** The leading bytes (and format version) of packed parameters */
+const PACKED_MAGIC: &[u8; 5] = b"JKD6\x01";
+
+/** The number of bytes describing each run of dimensions of the same degree */
+const RUN_LEN: usize = 5;
+
+
+/**
+ * Parameter values in the compact binary format generated at build time from the tables of Joe
+ * and Kuo (see `build.rs`). Values are read in place from a `&'static [u8]` without decompression
+ * or parsing, and each dimension is decoded only as its values are requested, so only the
+ * dimensions actually used are touched.
+ */
+pub struct PackedParams {
+    dims: Vec<PackedDim>
+}
+
+impl PackedParams {
+
+    /**
+     * Packed parameter values supporting up to 1000 dimensions
+     */
+    #[cfg(feature = "params-standard")]
+    pub fn standard() -> Self {
+        PackedParams::load_embedded(include_bytes!(concat!(env!("OUT_DIR"), "/new-joe-kuo-6.1000.bin")))
+    }
+
+    /**
+     * Packed parameter values supporting up to 100 dimensions
+     */
+    #[cfg(feature = "params-minimal")]
+    pub fn minimal() -> Self {
+        PackedParams::load_embedded(include_bytes!(concat!(env!("OUT_DIR"), "/new-joe-kuo-6.100.bin")))
+    }
+
+    /**
+     * Packed parameter values supporting up to 21,201 dimensions
+     */
+    #[cfg(feature = "params-extended")]
+    pub fn extended() -> Self {
+        PackedParams::load_embedded(include_bytes!(concat!(env!("OUT_DIR"), "/new-joe-kuo-6.21201.bin")))
+    }
+
+    /**
+     * Reads packed parameter values, checking only that the layout is consistent with the length
+     * of the data (the values themselves aren't read until requested)
+     */
+    pub fn from_bytes(bytes: &'static [u8]) -> Result<Self, ParamsError> {
+        if !bytes.starts_with(PACKED_MAGIC) {
+            return Err(ParamsError::packed(0, "missing header"));
+        }
+        let header = PACKED_MAGIC.len() + 4;
+        let n_runs = read_u32(bytes, PACKED_MAGIC.len())? as usize;
+        let runs = bytes.get(header .. header.saturating_add(n_runs.saturating_mul(RUN_LEN)))
+            .ok_or_else(|| ParamsError::packed(bytes.len(), "truncated run descriptions"))?;
+
+        let mut dims = vec![];
+        let mut offset = header + runs.len();
+        for (r, run) in runs.chunks_exact(RUN_LEN).enumerate() {
+            let s = run[0] as usize;
+            let count = u32::from_le_bytes(run[1 ..].try_into().unwrap()) as usize;
+            if s == 0 || s > 32 {
+                return Err(ParamsError::packed(header + r * RUN_LEN, format!("invalid degree {}", s)));
+            }
+            if dims.len() + count > u16::MAX as usize - 1 {
+                return Err(ParamsError::packed(header + r * RUN_LEN + 1, "too many dimensions"));
+            }
+
+            let bits = s - 1 + s * (s + 1) / 2;
+            let data = bytes.get(offset .. offset + (count * bits + 7) / 8)
+                .ok_or_else(|| ParamsError::packed(bytes.len(), "truncated dimension data"))?;
+            for i in 0 .. count {
+                dims.push(PackedDim { data, bit: (i * bits) as u32, d: dims.len() as u16 + 2, s: s as u8 });
+            }
+            offset += data.len();
+        }
+
+        if offset != bytes.len() {
+            return Err(ParamsError::packed(offset, "unexpected trailing data"));
+        }
+        Ok(PackedParams { dims })
+    }
+
+    /** Decodes the values of every dimension */
+    pub fn unpack(&self) -> JoeKuoD6 {
+        let dim_params = self.dims.iter()
+            .map(|p| JoeKuoD6Dim { d: p.d, a: p.a(), m: (0 .. p.s()).map(|i| p.m(i)).collect() })
+            .collect::<Vec<_>>();
+        let max_dims = dim_params.len() + 1;
+        JoeKuoD6 { dim_params, max_dims }
+    }
+
+    /** Instantiates parameter struct from the packed tables generated at build time */
+    #[cfg(any(feature = "params-minimal", feature = "params-standard", feature = "params-extended"))]
+    fn load_embedded(bytes: &'static [u8]) -> Self {
+        PackedParams::from_bytes(bytes).unwrap_or_else(|err| panic!("Embedded parameters are invalid: {}", err))
+    }
+}
+
+impl SobolParams<u32> for PackedParams {
+    #[inline]
+    fn get_dim(&self, dim: usize) -> &dyn ParamDimension<u32> {
+        &self.dims[dim - 2]
+    }
+
+    #[inline]
+    fn max_dims(&self) -> usize {
+        self.dims.len() + 1
+    }
+}
+
+/**
+ * The packed parameters of a single dimension, being `s - 1` bits of coefficients followed by `i`
+ * bits for each initial direction value `m_i`, from the given bit of the data
+ */
+struct PackedDim {
+    data: &'static [u8],
+    bit: u32,
+    d: u16,
+    s: u8
+}
+
+impl PackedDim {
+
+    /** The coefficients of the polynomial */
+    #[inline]
+    fn a(&self) -> u32 {
+        read_bits(self.data, self.bit as usize, self.s as usize - 1)
+    }
+}
+
+impl ParamDimension<u32> for PackedDim {
+
+    #[inline]
+    fn d(&self) -> u16 {
+        self.d
+    }
+
+    #[inline]
+    fn s(&self) -> usize {
+        self.s as usize
+    }
+
+    #[inline]
+    fn coefficient(&self, i: usize) -> u32 {
+        (self.a() >> i) & 1
+    }
+
+    #[inline]
+    fn m(&self, i: usize) -> u32 {
+        let bit = self.bit as usize + self.s as usize - 1 + i * (i + 1) / 2;
+        read_bits(self.data, bit, i + 1)
+    }
+}
+
+/** Reads a value of up to 32 bits from the given bit of the data, least significant bit first */
+#[inline]
+fn read_bits(data: &[u8], bit: usize, width: usize) -> u32 {
+    let bytes = &data[bit / 8 .. (bit + width + 7) / 8];
+    let val = bytes.iter().rev().fold(0u64, |val, b| (val << 8) | *b as u64);
+    ((val >> (bit % 8)) & ((1u64 << width) - 1)) as u32
+}
+
+/** Reads a little endian `u32` from the given byte offset */
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, ParamsError> {
+    bytes.get(offset .. offset + 4)
+        .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+        .ok_or_else(|| ParamsError::packed(bytes.len(), "truncated header"))
+}
@@ -0,0 +1,190 @@
+use crate::{SobolParams, LossyFrom};
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::mem::size_of;
+
+
+/** The greatest degree of polynomial whose primitivity can be verified */
+pub const MAX_VALIDATED_DEGREE: usize = 32;
+
+
+/**
+ * A defect in the parameters of a single (one-based) dimension, which would otherwise silently
+ * produce a degenerate sequence
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub dim: usize,
+    pub kind: ViolationKind
+}
+
+/** The kinds of defect found by `SobolParams::validate` */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ViolationKind {
+
+    /** The dimension reports a different index `d` than that at which it is found */
+    DimensionMismatch { d: u16 },
+
+    /** The degree `s` is zero or exceeds `MAX_VALIDATED_DEGREE` */
+    InvalidDegree { s: usize },
+
+    /** Coefficient bits are set beyond the `s - 1` inner coefficients of a polynomial of degree `s` */
+    CoefficientOutOfRange,
+
+    /** The polynomial is not primitive over GF(2) */
+    NotPrimitive,
+
+    /** There are `values` initial direction values rather than one for each of the `s` degrees */
+    DirectionValueCount { s: usize, values: usize },
+
+    /** The initial direction value `m_i` (one-based) is even */
+    EvenDirectionValue { i: usize },
+
+    /** The initial direction value `m_i` (one-based) is not less than `2^i` */
+    DirectionValueTooLarge { i: usize },
+
+    /** The polynomial is the same as that of the given (earlier) dimension */
+    DuplicatePolynomial { dim: usize }
+}
+
+impl fmt::Display for Violation {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Dimension {}: ", self.dim)?;
+        match self.kind {
+            ViolationKind::DimensionMismatch { d } => write!(f, "parameters are for dimension {}", d),
+            ViolationKind::InvalidDegree { s } =>
+                write!(f, "degree {} is not between 1 and {}", s, MAX_VALIDATED_DEGREE),
+            ViolationKind::CoefficientOutOfRange => write!(f, "coefficients exceed the degree of the polynomial"),
+            ViolationKind::NotPrimitive => write!(f, "polynomial is not primitive"),
+            ViolationKind::DirectionValueCount { s, values } =>
+                write!(f, "expected {} initial direction values for degree {} but found {}", s, s, values),
+            ViolationKind::EvenDirectionValue { i } => write!(f, "m_{} is even", i),
+            ViolationKind::DirectionValueTooLarge { i } => write!(f, "m_{} is not less than 2^{}", i, i),
+            ViolationKind::DuplicatePolynomial { dim } => write!(f, "polynomial is the same as that of dimension {}", dim)
+        }
+    }
+}
+
+impl Error for Violation {}
+
+/**
+ * Checks the parameters of every dimension beyond the first (whose direction values are fixed),
+ * collecting all violations in order of dimension
+ */
+pub(crate) fn validate<P, S>(params: &S) -> Result<(), Vec<Violation>>
+    where S: SobolParams<P> + ?Sized, u128: LossyFrom<P> {
+
+    let mut violations = vec![];
+    let mut polys: HashMap<u64, usize> = HashMap::new();
+    let mut factors: HashMap<usize, Vec<u64>> = HashMap::new();
+
+    for dim in 2 ..= params.max_dims() {
+        let p = params.get_dim(dim);
+        let mut report = |kind| violations.push(Violation { dim, kind });
+
+        if p.d() as usize != dim {
+            report(ViolationKind::DimensionMismatch { d: p.d() });
+        }
+
+        let s = p.s();
+        if s == 0 || s > MAX_VALIDATED_DEGREE {
+            report(ViolationKind::InvalidDegree { s });
+            continue;
+        }
+
+        let values = p.m_len();
+        if values != s {
+            report(ViolationKind::DirectionValueCount { s, values });
+        }
+        for i in 1 ..= s.min(values) {
+            let m = u128::lossy_from(p.m(i - 1));
+            if m & 1 == 0 {
+                report(ViolationKind::EvenDirectionValue { i });
+            }
+            if m >> i != 0 {
+                report(ViolationKind::DirectionValueTooLarge { i });
+            }
+        }
+
+        /* The polynomial x^s + a_1 x^(s-1) + ... + a_(s-1) x + 1, with a_k in bit s-1-k of `a` */
+        if (s - 1 .. size_of::<P>() * 8).any(|i| u128::lossy_from(p.coefficient(i)) != 0) {
+            report(ViolationKind::CoefficientOutOfRange);
+        }
+        let inner = (0 .. s - 1).fold(0u64, |a, i| a | ((u128::lossy_from(p.coefficient(i)) as u64 & 1) << i));
+        let poly = (1 << s) | (inner << 1) | 1;
+
+        let factors = factors.entry(s).or_insert_with(|| prime_factors((1 << s) - 1));
+        if !is_primitive(poly, s, factors) {
+            report(ViolationKind::NotPrimitive);
+        }
+        match polys.get(&poly) {
+            Some(&first) => report(ViolationKind::DuplicatePolynomial { dim: first }),
+            None => { polys.insert(poly, dim); }
+        }
+    }
+
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+/**
+ * Determines whether a polynomial of degree `s` (with non-zero constant term) is primitive, i.e.
+ * whether `x` has multiplicative order `2^s - 1` modulo the polynomial, given the prime factors
+ * of that order
+ */
+fn is_primitive(poly: u64, s: usize, factors: &[u64]) -> bool {
+    let order = (1u64 << s) - 1;
+    let one = 1 % poly;
+    pow_mod(0b10, order, poly, s) == one
+        && factors.iter().all(|q| pow_mod(0b10, order / q, poly, s) != one)
+}
+
+/** Raises the polynomial `base` to the power `exp` modulo `poly` of degree `s` over GF(2) */
+fn pow_mod(base: u64, exp: u64, poly: u64, s: usize) -> u64 {
+    let mut result = 1 % poly;
+    let mut base = mul_mod(base, 1, poly, s);
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, poly, s);
+        }
+        base = mul_mod(base, base, poly, s);
+        exp >>= 1;
+    }
+    result
+}
+
+/** Multiplies polynomials `a` and `b` modulo `poly` of degree `s` over GF(2) */
+fn mul_mod(a: u64, b: u64, poly: u64, s: usize) -> u64 {
+    let mut a = a;
+    let mut result = 0;
+    for i in 0 .. s {
+        if (a >> s) & 1 == 1 {
+            a ^= poly;
+        }
+        if (b >> i) & 1 == 1 {
+            result ^= a;
+        }
+        a <<= 1;
+    }
+    result
+}
+
+/** The distinct prime factors of `n`, by trial division */
+fn prime_factors(mut n: u64) -> Vec<u64> {
+    let mut factors = vec![];
+    let mut q = 2;
+    while q * q <= n {
+        if n % q == 0 {
+            factors.push(q);
+            while n % q == 0 { n /= q; }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
@@ -0,0 +1,277 @@
+use crate::InternalType;
+
+use std::ops::BitXor;
+
+use num_traits::{WrappingAdd, WrappingMul};
+
+
+/**
+ * Randomizations which may be applied to a sequence. Each is deterministic given its seed, so
+ * independent replicates are obtained by varying the seed.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Scrambling {
+
+    /** The plain, unrandomized sequence */
+    None,
+
+    /**
+     * Owen's nested uniform scrambling. The digits of each value are flipped according to a
+     * random permutation tree (one per dimension) such that each digit's permutation depends on
+     * all of the digits preceding it. The (t,m,s)-net properties of the sequence are preserved.
+     * Digits are scrambled to the full precision of the output type, so values of low resolution
+     * sequences are uniform within their cells.
+     */
+    Owen(u64),
+
+    /**
+     * A fast approximation of Owen scrambling using the hash-based permutation of Laine and
+     * Karras (as refined by Burley). Each value is scrambled with a handful of integer operations
+     * rather than one hash per digit, at some cost in the quality of the randomization. As with
+     * `Owen`, digits are scrambled to the full precision of the output type.
+     */
+    FastOwen(u64),
+
+    /**
+     * A random digital shift, in which each dimension is XORed with a random value. This is the
+     * simplest randomization which preserves the (t,m,s)-net properties of the sequence. The shift
+     * has the full precision of the output type, so even low resolution sequences are randomized
+     * within each cell.
+     */
+    DigitalShift(u64),
+
+    /**
+     * Matoušek's random linear scrambling, in which the generator matrix of each dimension is
+     * multiplied by a random nonsingular lower-triangular binary matrix. This is applied to the
+     * direction values once, so generation is just as fast as for the unscrambled sequence.
+     */
+    Linear(u64),
+
+    /** Random linear scrambling followed by a random digital shift (i.e. affine scrambling) */
+    Affine(u64),
+
+    /**
+     * A Cranley-Patterson rotation, in which a random offset is added to each dimension modulo
+     * one. This is intended for floating-point sequences (for which values are rotated about the
+     * unit interval) but integer sequences are rotated about their natural range in the same way.
+     * Offsets are uniform to the full precision of the output type, independent of the resolution,
+     * and the addition is exact. Unlike the other schemes, the net structure of the sequence is
+     * not preserved.
+     */
+    Rotation(u64)
+}
+
+impl Scrambling {
+
+    /** The seed of the linear scrambling component of this scheme, if any */
+    fn linear_seed(self) -> Option<u64> {
+        match self {
+            Scrambling::Linear(seed) => Some(seed),
+            Scrambling::Affine(seed) => Some(mix(seed)),
+            _ => None
+        }
+    }
+
+    /** The seed of the per-dimension offsets of this scheme (i.e. shifts or rotations), if any */
+    fn shift_seed(self) -> Option<u64> {
+        match self {
+            Scrambling::DigitalShift(seed) | Scrambling::Affine(seed) | Scrambling::Rotation(seed) => Some(seed),
+            _ => None
+        }
+    }
+}
+
+/**
+ * Per-dimension state necessary to apply a `Scrambling` to the values of a sequence
+ */
+#[derive(Clone)]
+pub(crate) struct Scrambler<I> {
+    pub mode: Scrambling,
+    keys: Vec<u64>,
+    shifts: Vec<I>,
+    resolution: usize,
+    precision: usize
+}
+
+impl<I: InternalType> Scrambler<I> {
+
+    /**
+     * Prepares the scrambler for a sequence of given dimensionality and resolution. Scrambled
+     * digits, shifts and rotations extend to the full precision of the output type (e.g. 24 bits
+     * for `f32`) rather than the resolution, so that they randomize values within the cells of
+     * width `2^-res` too.
+     */
+    pub fn new(mode: Scrambling, dims: usize, resolution: usize, precision: usize) -> Self {
+        let keys = match mode {
+            Scrambling::Owen(seed) | Scrambling::FastOwen(seed) => {
+                let mut state = seed;
+                (0 .. dims).map(|_| split_mix(&mut state)).collect()
+            },
+            _ => vec![]
+        };
+        let shifts = match mode.shift_seed() {
+            Some(seed) => {
+                let mut state = seed;
+                (0 .. dims).map(|_| random_bits::<I>(&mut state) & leading_mask(precision)).collect()
+            },
+            None => vec![]
+        };
+        Scrambler { mode, keys, shifts, resolution, precision }
+    }
+
+    /**
+     * The number of leading bits of scrambled values which are significant, being the precision
+     * of the output type for schemes which randomize bits beyond the resolution
+     */
+    #[inline] pub fn output_resolution(&self) -> usize {
+        match self.mode {
+            Scrambling::None | Scrambling::Linear(_) => self.resolution,
+            _ => self.precision
+        }
+    }
+
+    /** Scrambles a single internal value of the given (zero-based) dimension */
+    #[inline] pub fn apply(&self, dim: usize, val: I) -> I {
+        match self.mode {
+            Scrambling::None => val,
+            Scrambling::Owen(_) => owen(val, self.keys[dim], self.precision),
+            Scrambling::FastOwen(_) => fast_owen(val, self.keys[dim], self.precision),
+            Scrambling::DigitalShift(_) | Scrambling::Affine(_) => val ^ self.shifts[dim],
+            Scrambling::Rotation(_) => val.wrapping_add(&self.shifts[dim]),
+            Scrambling::Linear(_) => val
+        }
+    }
+
+    /**
+     * Replaces the linear scrambling of the given direction values, undoing that of the current
+     * scheme (if any) before applying that of `next` (if any). Direction values are given as rows
+     * such that `dir_vals[i][dim]` is the `i`-th direction value of dimension `dim`.
+     */
+    pub fn rescramble_directions<R: AsMut<[I]>>(&self, next: Scrambling, dir_vals: &mut [R]) {
+        if let Some(seed) = self.mode.linear_seed() {
+            linear_scramble(dir_vals, seed, self.resolution, true);
+        }
+        if let Some(seed) = next.linear_seed() {
+            linear_scramble(dir_vals, seed, self.resolution, false);
+        }
+    }
+}
+
+/**
+ * Multiplies the generator matrix of each dimension by a random unit lower-triangular matrix (or
+ * by its inverse). Columns of the generator matrices are the direction values, with the leading
+ * bit as the first row. Row `r` of the scrambling matrix is stored as a mask over the leading `r`
+ * bits with the diagonal bit set.
+ */
+fn linear_scramble<I: InternalType, R: AsMut<[I]>>(dir_vals: &mut [R], seed: u64, resolution: usize, inverse: bool) {
+    let dims = dir_vals.first_mut().map_or(0, |dirs| dirs.as_mut().len());
+    let mut state = seed;
+    for dim in 0 .. dims {
+        let rows: Vec<I> = (0 .. resolution)
+            .map(|r| (random_bits::<I>(&mut state) & leading_mask(r)) | (I::one() << (I::BITS - r - 1)))
+            .collect();
+
+        for dirs in dir_vals.iter_mut() {
+            let dir = &mut dirs.as_mut()[dim];
+            let mut out = I::zero();
+            for (r, row) in rows.iter().enumerate() {
+                let bit = I::one() << (I::BITS - r - 1);
+                let parity = if inverse {
+                    /* Forward substitution, since leading bits of `out` are already solved */
+                    ((*dir & bit) ^ (*row & !bit & out)).count_ones() & 1
+                } else {
+                    (*row & *dir).count_ones() & 1
+                };
+                if parity == 1 {
+                    out = out | bit;
+                }
+            }
+            *dir = out;
+        }
+    }
+}
+
+/**
+ * Applies nested uniform scrambling to the leading `precision` bits of a value. Rather than
+ * storing the permutation tree, the flip for each node is drawn from a hash which is chained
+ * along the path of digits leading to that node.
+ */
+fn owen<I: InternalType>(val: I, key: u64, precision: usize) -> I {
+    let mut hash = mix(key);
+    let mut flips = I::zero();
+
+    for i in 1 ..= precision {
+        let bit = I::BITS - i;
+        if hash >> 63 == 1 {
+            flips = flips | (I::one() << bit);
+        }
+
+        let digit = (val >> bit) & I::one();
+        hash = mix(hash ^ if digit == I::zero() { DIGIT_0 } else { DIGIT_1 });
+    }
+
+    val ^ flips
+}
+
+/**
+ * Applies hash-based Owen scrambling to the leading `precision` bits of a value. The hash is
+ * computed on the bit-reversed value using only operations which propagate from lower to higher
+ * bits, so each digit is permuted according to the digits preceding it. Values of up to 64 bits
+ * are hashed in 64-bit arithmetic with wider values falling back to 128 bits.
+ */
+fn fast_owen<I: InternalType>(val: I, key: u64, precision: usize) -> I {
+    let rev = val.reverse_bits();
+    let hashed = if I::BITS <= 64 {
+        I::from(laine_karras(rev.to_u64().unwrap(), key) & (u64::MAX >> (64 - I::BITS)))
+    } else {
+        I::from(laine_karras(rev.to_u128().unwrap(), key))
+    };
+
+    hashed.unwrap().reverse_bits() & leading_mask(precision)
+}
+
+/** A mask selecting the leading `n` bits of a value */
+#[inline] pub(crate) fn leading_mask<I: InternalType>(n: usize) -> I {
+    match n {
+        0 => I::zero(),
+        n => !(I::max_value() >> (n - 1) >> 1)
+    }
+}
+
+/** The Laine-Karras style permutation of Burley's "Practical Hash-based Owen Scrambling" */
+#[inline] fn laine_karras<W>(mut x: W, seed: u64) -> W
+    where W: Copy + BitXor<Output = W> + WrappingAdd + WrappingMul + From<u64> {
+
+    x = x ^ x.wrapping_mul(&W::from(0x3d20_adea));
+    x = x.wrapping_add(&W::from(seed));
+    x = x.wrapping_mul(&W::from((seed >> 16) | 1));
+    x = x ^ x.wrapping_mul(&W::from(0x0552_6c56));
+    x ^ x.wrapping_mul(&W::from(0x53a2_2864))
+}
+
+/** Constants distinguishing the two branches at each node of a permutation tree */
+const DIGIT_0: u64 = 0x8cb9_2ba7_2f3d_8dd7;
+const DIGIT_1: u64 = 0xd1b5_4a32_d192_ed03;
+
+/** Draws a value with all bits random from a SplitMix64 stream */
+fn random_bits<I: InternalType>(state: &mut u64) -> I {
+    if I::BITS <= 64 {
+        I::from(split_mix(state) >> (64 - I::BITS)).unwrap()
+    } else {
+        let hi = I::from(split_mix(state)).unwrap();
+        (hi << 64) | I::from(split_mix(state)).unwrap()
+    }
+}
+
+/** Advances the given state and returns the next value of a SplitMix64 stream */
+pub(crate) fn split_mix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    mix(*state)
+}
+
+/** The finalizer of SplitMix64, a fast bijective mixing function */
+#[inline] fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,244 @@
+use crate::SobolType;
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(target_arch = "x86_64")]
+use std::mem;
+#[cfg(target_arch = "x86_64")]
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+
+/**
+ * Defines a function which dispatches to an AVX2 kernel, if the CPU supports it, or otherwise to
+ * a scalar kernel. Detection happens once, on the first call, after which the chosen kernel is
+ * cached as a function pointer so that later calls involve no further branching.
+ */
+macro_rules! dispatch {
+    ($(#[$doc:meta])* fn $name:ident($($arg:ident: $ty:ty),*) => $scalar:ident | $avx2:ident) => {
+        $(#[$doc])*
+        pub(crate) fn $name($($arg: $ty),*) {
+            #[cfg(target_arch = "x86_64")] {
+                type Kernel = fn($($ty),*);
+                static KERNEL: AtomicPtr<()> = AtomicPtr::new(detect as *mut ());
+
+                fn detect($($arg: $ty),*) {
+                    let kernel: Kernel = if is_x86_feature_detected!("avx2") {
+                        |$($arg),*| unsafe { $avx2($($arg),*) }
+                    } else { $scalar };
+                    KERNEL.store(kernel as *mut (), Ordering::Relaxed);
+                    kernel($($arg),*)
+                }
+
+                /* The pointer is only ever that of a `Kernel` */
+                let kernel = unsafe { mem::transmute::<*mut (), Kernel>(KERNEL.load(Ordering::Relaxed)) };
+                kernel($($arg),*)
+            }
+            #[cfg(not(target_arch = "x86_64"))]
+            $scalar($($arg),*)
+        }
+    }
+}
+
+dispatch! {
+    /**
+     * XORs each value of `src` into the corresponding value of `dst`. This and the following
+     * functions make use of AVX2 when it is detected at runtime, otherwise falling back to scalar
+     * code.
+     */
+    fn xor_assign_u32(dst: &mut [u32], src: &[u32]) => xor_assign_scalar | xor_assign_u32_avx2
+}
+
+dispatch! {
+    /** XORs each value of `src` into the corresponding value of `dst` */
+    fn xor_assign_u64(dst: &mut [u64], src: &[u64]) => xor_assign_scalar | xor_assign_u64_avx2
+}
+
+dispatch! {
+    /** Renders internal `u32` values as `f32` values in the unit interval */
+    fn render_f32(vals: &[u32], out: &mut [f32]) => render_f32_scalar | render_f32_avx2
+}
+
+dispatch! {
+    /** Renders internal `u64` values as `f64` values in the unit interval */
+    fn render_f64(vals: &[u64], out: &mut [f64]) => render_f64_scalar | render_f64_avx2
+}
+
+
+/** The scale which maps a 32-bit internal value to the unit interval */
+#[cfg(target_arch = "x86_64")]
+const SCALE_F32: f32 = 1.0 / 4_294_967_296_f32;
+
+/** The scale which maps a 64-bit internal value to the unit interval */
+#[cfg(target_arch = "x86_64")]
+const SCALE_F64: f64 = 1.0 / 18_446_744_073_709_551_616_f64;
+
+fn xor_assign_scalar<I: Copy + std::ops::BitXorAssign>(dst: &mut [I], src: &[I]) {
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d ^= *s;
+    }
+}
+
+fn render_f32_scalar(vals: &[u32], out: &mut [f32]) {
+    for (v, o) in vals.iter().zip(out.iter_mut()) {
+        *o = f32::render(*v);
+    }
+}
+
+fn render_f64_scalar(vals: &[u64], out: &mut [f64]) {
+    for (v, o) in vals.iter().zip(out.iter_mut()) {
+        *o = f64::render(*v);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn xor_assign_u32_avx2(dst: &mut [u32], src: &[u32]) {
+    let len = dst.len().min(src.len());
+    let vec_len = len - len % 8;
+    for i in (0 .. vec_len).step_by(8) {
+        let d = _mm256_loadu_si256(dst.as_ptr().add(i) as *const __m256i);
+        let s = _mm256_loadu_si256(src.as_ptr().add(i) as *const __m256i);
+        _mm256_storeu_si256(dst.as_mut_ptr().add(i) as *mut __m256i, _mm256_xor_si256(d, s));
+    }
+    xor_assign_scalar(&mut dst[vec_len .. len], &src[vec_len .. len]);
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn xor_assign_u64_avx2(dst: &mut [u64], src: &[u64]) {
+    let len = dst.len().min(src.len());
+    let vec_len = len - len % 4;
+    for i in (0 .. vec_len).step_by(4) {
+        let d = _mm256_loadu_si256(dst.as_ptr().add(i) as *const __m256i);
+        let s = _mm256_loadu_si256(src.as_ptr().add(i) as *const __m256i);
+        _mm256_storeu_si256(dst.as_mut_ptr().add(i) as *mut __m256i, _mm256_xor_si256(d, s));
+    }
+    xor_assign_scalar(&mut dst[vec_len .. len], &src[vec_len .. len]);
+}
+
+/**
+ * Since AVX2 only converts signed integers, each value is split into 16-bit halves which are
+ * converted separately. Recombining them involves a single rounding, so the result is identical
+ * to that of the scalar conversion.
+ */
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn render_f32_avx2(vals: &[u32], out: &mut [f32]) {
+    let len = vals.len().min(out.len());
+    let vec_len = len - len % 8;
+    let low_mask = _mm256_set1_epi32(0xffff);
+    let hi_scale = _mm256_set1_ps(65_536.0);
+    let scale = _mm256_set1_ps(SCALE_F32);
+    for i in (0 .. vec_len).step_by(8) {
+        let v = _mm256_loadu_si256(vals.as_ptr().add(i) as *const __m256i);
+        let hi = _mm256_cvtepi32_ps(_mm256_srli_epi32(v, 16));
+        let lo = _mm256_cvtepi32_ps(_mm256_and_si256(v, low_mask));
+        let f = _mm256_add_ps(_mm256_mul_ps(hi, hi_scale), lo);
+        _mm256_storeu_ps(out.as_mut_ptr().add(i), _mm256_mul_ps(f, scale));
+    }
+    render_f32_scalar(&vals[vec_len .. len], &mut out[vec_len .. len]);
+}
+
+/**
+ * AVX2 lacks any conversion from 64-bit integers, so each value is split into 32-bit halves which
+ * are embedded in the significands of doubles of known exponent (2^84 and 2^52 respectively).
+ * Subtracting the exponents and recombining involves a single rounding, so the result is
+ * identical to that of the scalar conversion.
+ */
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn render_f64_avx2(vals: &[u64], out: &mut [f64]) {
+    let len = vals.len().min(out.len());
+    let vec_len = len - len % 4;
+    let hi_exp = _mm256_set1_epi64x(0x4530_0000_0000_0000);
+    let lo_exp = _mm256_set1_epi64x(0x4330_0000_0000_0000);
+    let exps = _mm256_set1_pd(f64::from_bits(0x4530_0000_0010_0000));
+    let scale = _mm256_set1_pd(SCALE_F64);
+    for i in (0 .. vec_len).step_by(4) {
+        let v = _mm256_loadu_si256(vals.as_ptr().add(i) as *const __m256i);
+        let hi = _mm256_castsi256_pd(_mm256_or_si256(_mm256_srli_epi64(v, 32), hi_exp));
+        let lo = _mm256_castsi256_pd(_mm256_blend_epi32(v, lo_exp, 0b1010_1010));
+        let f = _mm256_add_pd(_mm256_sub_pd(hi, exps), lo);
+        _mm256_storeu_pd(out.as_mut_ptr().add(i), _mm256_mul_pd(f, scale));
+    }
+    render_f64_scalar(&vals[vec_len .. len], &mut out[vec_len .. len]);
+}
+
+
+/**
+ * The kernels are private, so they're tested here directly rather than through the dispatching
+ * functions (which would only ever exercise the AVX2 kernels on hosts which support it)
+ */
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /** Edge cases and pseudo-random values, of a length which leaves a remainder for every width */
+    fn test_values() -> Vec<u64> {
+        let edges = [0, 1, 2, 0xffff, 0x1_0000, 0xffff_ffff, 0x1_0000_0000, u64::MAX, u64::MAX - 1, 1 << 63];
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let random = (0 .. 1001).map(move |_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        });
+        edges.iter().copied().chain(random).collect()
+    }
+
+    #[test] fn test_xor_assign_scalar() {
+        let src = test_values();
+        let mut dst = src.iter().map(|v| v.rotate_left(17)).collect::<Vec<_>>();
+        let expected = dst.iter().zip(&src).map(|(d, s)| d ^ s).collect::<Vec<_>>();
+        xor_assign_scalar(&mut dst, &src);
+        assert_eq!(dst, expected);
+    }
+
+    #[test] fn test_render_scalar() {
+        let vals = test_values();
+        let mut out = vec![0f64; vals.len()];
+        render_f64_scalar(&vals, &mut out);
+        assert!(vals.iter().zip(&out).all(|(v, o)| *o == *v as f64 / 18_446_744_073_709_551_616_f64));
+
+        let vals = vals.iter().map(|v| (v >> 32) as u32).collect::<Vec<_>>();
+        let mut out = vec![0f32; vals.len()];
+        render_f32_scalar(&vals, &mut out);
+        assert!(vals.iter().zip(&out).all(|(v, o)| *o == *v as f32 / 4_294_967_296_f32));
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test] fn test_xor_assign_avx2() {
+        if !is_x86_feature_detected!("avx2") { return; }
+
+        let src = test_values();
+        let mut dst = src.iter().map(|v| v.rotate_left(17)).collect::<Vec<_>>();
+        let mut expected = dst.clone();
+        xor_assign_scalar(&mut expected, &src);
+        unsafe { xor_assign_u64_avx2(&mut dst, &src) };
+        assert_eq!(dst, expected);
+
+        let src = src.iter().map(|v| *v as u32).collect::<Vec<_>>();
+        let mut dst = src.iter().map(|v| v.rotate_left(9)).collect::<Vec<_>>();
+        let mut expected = dst.clone();
+        xor_assign_scalar(&mut expected, &src);
+        unsafe { xor_assign_u32_avx2(&mut dst, &src) };
+        assert_eq!(dst, expected);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test] fn test_render_avx2() {
+        if !is_x86_feature_detected!("avx2") { return; }
+
+        let vals = test_values();
+        let (mut out, mut expected) = (vec![0f64; vals.len()], vec![0f64; vals.len()]);
+        render_f64_scalar(&vals, &mut expected);
+        unsafe { render_f64_avx2(&vals, &mut out) };
+        assert!(out.iter().zip(&expected).all(|(o, e)| o.to_bits() == e.to_bits()));
+
+        let vals = vals.iter().map(|v| (v >> 32) as u32).collect::<Vec<_>>();
+        let (mut out, mut expected) = (vec![0f32; vals.len()], vec![0f32; vals.len()]);
+        render_f32_scalar(&vals, &mut expected);
+        unsafe { render_f32_avx2(&vals, &mut out) };
+        assert!(out.iter().zip(&expected).all(|(o, e)| o.to_bits() == e.to_bits()));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Sobol, SobolType, remaining_hint, remaining_len};
+
+use std::iter::FusedIterator;
+use std::sync::Arc;
+
+use num_traits::Zero;
+
+
+/**
+ * The direction values and configuration of a sequence, shared between any number of cursors.
+ * Cloning a table is cheap, since only a reference count is incremented, and each cursor holds
+ * just the state of a single point. This is useful when many threads generate points of the same
+ * (perhaps very high-dimensional) sequence, which would otherwise each own a copy of the table.
+ */
+pub struct SobolTable<T: SobolType> {
+    seq: Arc<Sobol<T>>,
+    start: u128
+}
+
+impl<T: SobolType> SobolTable<T> {
+
+    /**
+     * Returns a cursor positioned at the starting point of the table (i.e. the position of the
+     * sequence from which the table was made)
+     **/
+    pub fn cursor(&self) -> SobolCursor<T> {
+        self.cursor_at(self.start)
+    }
+
+    /**
+     * Returns a cursor positioned such that the next point produced is the one at the given
+     * zero-based index
+     **/
+    pub fn cursor_at(&self, index: u128) -> SobolCursor<T> {
+        let mut cursor = SobolCursor {
+            table: self.clone(),
+            state: vec![T::IT::zero(); self.seq.dims],
+            count: 0
+        };
+        cursor.skip_to(index);
+        cursor
+    }
+
+    /**
+     * Returns the point at the given zero-based index, or `None` if the index lies beyond the end
+     * of the sequence
+     **/
+    pub fn point_at(&self, index: u128) -> Option<Vec<T>> {
+        self.seq.point_at(index)
+    }
+
+    /** The number of dimensions of the sequence */
+    pub fn dims(&self) -> usize {
+        self.seq.dims
+    }
+
+    /** The number of points in the sequence */
+    pub fn max_len(&self) -> u128 {
+        self.seq.max_len
+    }
+}
+
+impl<T: SobolType> Clone for SobolTable<T> {
+
+    fn clone(&self) -> Self {
+        SobolTable { seq: Arc::clone(&self.seq), start: self.start }
+    }
+}
+
+impl<T: SobolType> From<Sobol<T>> for SobolTable<T> {
+
+    fn from(seq: Sobol<T>) -> Self {
+        let start = seq.count;
+        SobolTable { seq: Arc::new(seq), start }
+    }
+}
+
+/**
+ * An iterator over the points of a shared `SobolTable`, with its own position in the sequence
+ */
+pub struct SobolCursor<T: SobolType> {
+    table: SobolTable<T>,
+    state: Vec<T::IT>,
+    count: u128
+}
+
+impl<T: SobolType> SobolCursor<T> {
+
+    /** The zero-based index of the next point to be produced */
+    pub fn position(&self) -> u128 {
+        self.count
+    }
+
+    /**
+     * Positions the cursor such that the next point produced is the one at the given zero-based
+     * index (see `Sobol::skip_to`)
+     **/
+    pub fn skip_to(&mut self, index: u128) {
+        let seq = &self.table.seq;
+        let index = index.min(seq.max_len);
+
+        if index > 0 {
+            seq.point_vals_into(index - 1, &mut self.state);
+        } else {
+            self.state.iter_mut().for_each(|v| *v = T::IT::zero());
+        }
+        self.count = index;
+    }
+
+    /**
+     * Writes the next point into the given buffer, which must be exactly `dims` in length (see
+     * `Sobol::next_into`)
+     **/
+    pub fn next_into(&mut self, out: &mut [T]) -> bool {
+        assert_eq!(out.len(), self.state.len(), "Buffer must have length equal to the number of dimensions");
+
+        let advanced = self.advance();
+        if advanced {
+            self.table.seq.render_into(&self.state, out);
+        }
+        advanced
+    }
+
+    /**
+     * Writes up to `n_points` successive points into the given buffer in row-major order (see
+     * `Sobol::fill`)
+     **/
+    pub fn fill(&mut self, out: &mut [T], n_points: usize) -> usize {
+        let dims = self.state.len();
+        assert!(out.len() >= n_points * dims, "Buffer is too small to hold {} points", n_points);
+
+        let mut written = 0;
+        for point in out.chunks_exact_mut(dims).take(n_points) {
+            if !self.advance() { break; }
+            self.table.seq.render_into(&self.state, point);
+            written += 1;
+        }
+        written
+    }
+
+    /** Updates the state to that of the next point, returning `false` if the sequence is exhausted */
+    #[inline] fn advance(&mut self) -> bool {
+        if self.count >= self.table.seq.max_len {
+            return false;
+        }
+
+        self.table.seq.step(&mut self.state, self.count);
+        self.count += 1;
+        true
+    }
+}
+
+impl<T: SobolType> Iterator for SobolCursor<T> {
+
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.advance() {
+            Some(self.table.seq.render(&self.state))
+        } else { None }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining_hint(self.table.seq.max_len - self.count)
+    }
+
+    fn count(self) -> usize {
+        remaining_len(self.table.seq.max_len - self.count)
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        if self.count < self.table.seq.max_len {
+            self.table.point_at(self.table.seq.max_len - 1)
+        } else { None }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.skip_to(self.count.saturating_add(n as u128));
+        self.next()
+    }
+}
+
+impl<T: SobolType> FusedIterator for SobolCursor<T> {}
@@ -1,8 +1,5 @@
-use crate::{SobolType, InternalType, LossyFrom};
-
-use std::{u8, u16, u32, u64, u128};
-use std::{i8, i16, i32, i64, i128};
-use std::{f32, f64};
+use crate::{Sobol, SobolCursor, SobolType, InternalType, Interval, LossyFrom};
+use crate::simd;
 
 
 /** SobolType implementation for 32-bit floating-point values */
@@ -12,6 +9,22 @@ impl SobolType for f32 {
     fn render(val: u32) -> f32 {
         (val as f32) / 4_294_967_296_f32
     }
+    fn render_slice(vals: &[u32], out: &mut [f32]) {
+        simd::render_f32(vals, out)
+    }
+    fn render_interval(val: u32, resolution: usize, interval: Interval) -> f32 {
+        match interval {
+            Interval::ClosedOpen => Self::render(val),
+            Interval::Open => {
+                let res = resolution.min(Self::MAX_RESOLUTION - 1);
+                (((val >> (32 - res)) << 1) + 1) as f32 / (1u64 << (res + 1)) as f32
+            },
+            Interval::OpenClosed => ((val >> (32 - resolution)) + 1) as f32 / (1u64 << resolution) as f32
+        }
+    }
+    fn rescale(self, lower: &f32, upper: &f32) -> f32 {
+        lower + (upper - lower) * self
+    }
 }
 
 /** SobolType implementation for 64-bit floating-point values */
@@ -21,6 +34,22 @@ impl SobolType for f64 {
     fn render(val: u64) -> f64 {
         (val as f64) / 18_446_744_073_709_551_616_f64
     }
+    fn render_slice(vals: &[u64], out: &mut [f64]) {
+        simd::render_f64(vals, out)
+    }
+    fn render_interval(val: u64, resolution: usize, interval: Interval) -> f64 {
+        match interval {
+            Interval::ClosedOpen => Self::render(val),
+            Interval::Open => {
+                let res = resolution.min(Self::MAX_RESOLUTION - 1);
+                (((val >> (64 - res)) << 1) + 1) as f64 / (1u64 << (res + 1)) as f64
+            },
+            Interval::OpenClosed => ((val >> (64 - resolution)) + 1) as f64 / (1u64 << resolution) as f64
+        }
+    }
+    fn rescale(self, lower: &f64, upper: &f64) -> f64 {
+        lower + (upper - lower) * self
+    }
 }
 
 /** SobolType implementation for 8-bit unsigned values */
@@ -117,11 +146,17 @@ impl InternalType for u16 {
 /** InternalType implementation for 32-bit values */
 impl InternalType for u32 {
     const BITS: usize = 32;
+    fn xor_assign_slice(dst: &mut [u32], src: &[u32]) {
+        simd::xor_assign_u32(dst, src)
+    }
 }
 
 /** InternalType implementation for 64-bit values */
 impl InternalType for u64 {
     const BITS: usize = 64;
+    fn xor_assign_slice(dst: &mut [u64], src: &[u64]) {
+        simd::xor_assign_u64(dst, src)
+    }
 }
 
 /** InternalType implementation for 128-bit values */
@@ -130,6 +165,76 @@ impl InternalType for u128 {
 }
 
 
+/*
+ * Sequences of types narrower than 64 bits have at most `2^63` points, so their length is always
+ * representable as `usize` on 64-bit targets. Sequences of 64-bit integers may be longer.
+ */
+
+/** ExactSizeIterator implementation for 8-bit unsigned sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<u8> {}
+
+/** ExactSizeIterator implementation for 8-bit unsigned sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<u8> {}
+
+/** ExactSizeIterator implementation for 16-bit unsigned sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<u16> {}
+
+/** ExactSizeIterator implementation for 16-bit unsigned sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<u16> {}
+
+/** ExactSizeIterator implementation for 8-bit signed sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<i8> {}
+
+/** ExactSizeIterator implementation for 8-bit signed sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<i8> {}
+
+/** ExactSizeIterator implementation for 16-bit signed sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<i16> {}
+
+/** ExactSizeIterator implementation for 16-bit signed sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<i16> {}
+
+/** ExactSizeIterator implementation for 32-bit unsigned sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<u32> {}
+
+/** ExactSizeIterator implementation for 32-bit unsigned sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<u32> {}
+
+/** ExactSizeIterator implementation for 32-bit signed sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<i32> {}
+
+/** ExactSizeIterator implementation for 32-bit signed sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<i32> {}
+
+/** ExactSizeIterator implementation for 32-bit floating-point sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<f32> {}
+
+/** ExactSizeIterator implementation for 32-bit floating-point sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<f32> {}
+
+/** ExactSizeIterator implementation for 64-bit floating-point sequences */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for Sobol<f64> {}
+
+/** ExactSizeIterator implementation for 64-bit floating-point sequence cursors */
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for SobolCursor<f64> {}
+
+
 /** Reflexive `LossyFrom` */
 impl<T> LossyFrom<T> for T {
     fn lossy_from(val: T) -> T {
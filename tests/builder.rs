@@ -0,0 +1,98 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** The number of points to compare */
+const N: usize = 500;
+
+
+/** A builder configured like the constructors produces the same sequence */
+#[test] fn test_builder_defaults() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<u32>::builder(&params).dims(6).resolution(20).build().unwrap();
+    assert_eq!(seq.take(N).collect::<Vec<_>>(),
+               Sobol::<u32>::new_with_resolution(6, &params, Some(20)).take(N).collect::<Vec<_>>());
+}
+
+/** The starting index and skip count together position the sequence, after scrambling */
+#[test] fn test_builder_position() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<f64>::builder(&params)
+        .dims(4)
+        .scrambling(Scrambling::Affine(11))
+        .start(100)
+        .skip(28)
+        .build()
+        .unwrap();
+
+    let expected = Sobol::<f64>::new(4, &params).scrambled(Scrambling::Affine(11)).skip(128).take(N);
+    assert_eq!(seq.take(N).collect::<Vec<_>>(), expected.collect::<Vec<_>>());
+}
+
+/** Initial points may be discarded by a power of two or just the zero point */
+#[test] fn test_builder_skip() {
+    let params = JoeKuoD6::minimal();
+    let build = || Sobol::<f32>::builder(&params).dims(3);
+
+    let seq = build().skip_zero().build().unwrap();
+    assert_eq!(seq.count, 1);
+    assert!(seq.take(N).flatten().all(|v| v > 0.0));
+
+    let seq = build().skip_pow2(10).skip_zero().build().unwrap();
+    assert_eq!(seq.count, 1024);
+    assert_eq!(seq.take(N).collect::<Vec<_>>(), Sobol::<f32>::new(3, &params).skip(1024).take(N).collect::<Vec<_>>());
+
+    assert_eq!(build().skip_pow2(200).build().unwrap().next(), None);
+}
+
+/** A subset of dimensions yields the corresponding components of the full sequence */
+#[test] fn test_builder_dimensions() {
+    let params = JoeKuoD6::minimal();
+    let subset = [7, 0, 3];
+    let seq = Sobol::<u16>::builder(&params).dimensions(&subset).build().unwrap();
+    let full = Sobol::<u16>::new(8, &params);
+    for (p, q) in seq.zip(full).take(N) {
+        assert_eq!(p, subset.iter().map(|d| q[*d]).collect::<Vec<_>>());
+    }
+}
+
+/** Values are mapped onto the bounds of each dimension */
+#[test] fn test_builder_bounds() {
+    let params = JoeKuoD6::minimal();
+    let bounds = [(-1.0, 1.0), (10.0, 20.0)];
+    let seq = Sobol::<f64>::builder(&params).dims(2).bounds(&bounds).build().unwrap();
+    let unit = Sobol::<f64>::new(2, &params);
+    for (p, q) in seq.zip(unit).take(N) {
+        for ((v, u), (lo, hi)) in p.iter().zip(q).zip(bounds) {
+            assert_eq!(*v, lo + (hi - lo) * u);
+            assert!(*v >= lo && *v < hi);
+        }
+    }
+}
+
+/** Invalid configurations are reported when the sequence is built */
+#[test] fn test_builder_errors() {
+    let params = JoeKuoD6::minimal();
+    let max_dims = params.max_dims;
+    let build = || Sobol::<f32>::builder(&params);
+
+    assert_eq!(build().build().err(), Some(SobolError::ZeroDimensions));
+    assert_eq!(build().dims(max_dims + 1).build().err(),
+               Some(SobolError::TooManyDimensions { dims: max_dims + 1, max_dims }));
+    assert_eq!(build().dims(2).resolution(30).build().err(),
+               Some(SobolError::UnsupportedResolution { resolution: 30, max_resolution: 24 }));
+    assert_eq!(build().dimensions(&[1, max_dims]).build().err(),
+               Some(SobolError::InvalidDimension { dim: max_dims, max_dims }));
+    assert_eq!(build().dimensions(&[1, 2, 1]).build().err(),
+               Some(SobolError::InvalidDimension { dim: 1, max_dims }));
+    assert_eq!(build().dims(2).bounds(&[(0.0, 1.0), (1.0, 1.0)]).build().err(),
+               Some(SobolError::InvalidBounds { dim: 1 }));
+    assert_eq!(build().dims(2).bounds(&[(0.0, f32::INFINITY), (0.0, 1.0)]).build().err(),
+               Some(SobolError::InvalidBounds { dim: 0 }));
+    assert_eq!(build().dims(3).bounds(&[(0.0, 1.0)]).build().err(),
+               Some(SobolError::BoundsMismatch { bounds: 1, dims: 3 }));
+}
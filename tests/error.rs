@@ -0,0 +1,38 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** Valid configurations construct the same sequence as the panicking constructors */
+#[test] fn test_try_new() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<f32>::try_new(5, &params).unwrap();
+    assert_eq!(seq.take(100).collect::<Vec<_>>(), Sobol::<f32>::new(5, &params).take(100).collect::<Vec<_>>());
+
+    let seq = Sobol::<u16>::try_new_with_resolution(5, &params, Some(10)).unwrap();
+    assert_eq!(seq.resolution, 10);
+    assert_eq!(seq.collect::<Vec<_>>(), Sobol::<u16>::new_with_resolution(5, &params, Some(10)).collect::<Vec<_>>());
+}
+
+/** Invalid dimensionality is reported rather than panicking */
+#[test] fn test_try_new_dims() {
+    let params = JoeKuoD6::minimal();
+    assert_eq!(Sobol::<f32>::try_new(0, &params).err(), Some(SobolError::ZeroDimensions));
+    assert_eq!(Sobol::<f32>::try_new(params.max_dims + 1, &params).err(),
+               Some(SobolError::TooManyDimensions { dims: params.max_dims + 1, max_dims: params.max_dims }));
+    assert_eq!(SobolN::<f32, 0>::try_new(&params).err(), Some(SobolError::ZeroDimensions));
+}
+
+/** Resolutions which are zero or exceed the capacity of the type are reported */
+#[test] fn test_try_new_resolution() {
+    let params = JoeKuoD6::minimal();
+    for res in [0, 25] {
+        assert_eq!(Sobol::<f32>::try_new_with_resolution(3, &params, Some(res)).err(),
+                   Some(SobolError::UnsupportedResolution { resolution: res, max_resolution: 24 }));
+    }
+    assert_eq!(SobolN::<u8, 3>::try_new_with_resolution(&params, Some(9)).err(),
+               Some(SobolError::UnsupportedResolution { resolution: 9, max_resolution: 8 }));
+}
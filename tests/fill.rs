@@ -0,0 +1,110 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 12;
+
+/** The number of points to compare */
+const N: usize = 1000;
+
+
+/** Points written by `next_into` agree with those produced by the iterator */
+#[test] fn test_next_into() {
+    let params = JoeKuoD6::minimal();
+    let expected = Sobol::<f32>::new(DIMS, &params).take(N).collect::<Vec<_>>();
+
+    let mut seq = Sobol::<f32>::new(DIMS, &params);
+    let mut buf = vec![0f32; DIMS];
+    for point in expected {
+        assert!(seq.next_into(&mut buf));
+        assert_eq!(buf, point);
+    }
+}
+
+/** Points written by `fill` agree with those produced by the iterator, in row-major order */
+#[test] fn test_fill() {
+    let params = JoeKuoD6::minimal();
+    let expected = Sobol::<u64>::new(DIMS, &params)
+        .scrambled(Scrambling::Owen(1))
+        .take(N)
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut seq = Sobol::<u64>::new(DIMS, &params).scrambled(Scrambling::Owen(1));
+    let mut buf = vec![0u64; N * DIMS];
+    assert_eq!(seq.fill(&mut buf[.. 300 * DIMS], 300), 300);
+    assert_eq!(seq.fill(&mut buf[300 * DIMS ..], N - 300), N - 300);
+    assert_eq!(buf, expected);
+}
+
+/** Filling stops at the end of the sequence */
+#[test] fn test_fill_exhausted() {
+    let params = JoeKuoD6::minimal();
+    let len = Sobol::<u8>::new(DIMS, &params).count();
+
+    let mut seq = Sobol::<u8>::new(DIMS, &params);
+    let mut buf = vec![0u8; 1000 * DIMS];
+    assert_eq!(seq.fill(&mut buf, 1000), len);
+    assert_eq!(seq.fill(&mut buf, 1000), 0);
+    assert!(!seq.next_into(&mut buf[.. DIMS]));
+}
+
+/** Points written by `fill_columns` are the transpose of those produced by the iterator */
+#[test] fn test_fill_columns() {
+    let params = JoeKuoD6::minimal();
+    let expected = Sobol::<f64>::new(DIMS, &params)
+        .scrambled(Scrambling::DigitalShift(4))
+        .take(N)
+        .collect::<Vec<_>>();
+
+    let mut seq = Sobol::<f64>::new(DIMS, &params).scrambled(Scrambling::DigitalShift(4));
+    let mut buf = vec![0f64; 500 * DIMS];
+    for block in 0 .. N / 500 {
+        assert_eq!(seq.fill_columns(&mut buf, 500), 500);
+        for (i, point) in expected[block * 500 .. (block + 1) * 500].iter().enumerate() {
+            for (dim, v) in point.iter().enumerate() {
+                assert_eq!(buf[dim * 500 + i], *v);
+            }
+        }
+    }
+    assert_eq!(seq.next(), Sobol::<f64>::new(DIMS, &params).scrambled(Scrambling::DigitalShift(4)).nth(N));
+}
+
+/** Columns agree with the iterator for small, unaligned batches and resolutions shorter than a run */
+#[test] fn test_fill_columns_unaligned() {
+    let params = JoeKuoD6::minimal();
+    for res in [2, 5, 7] {
+        let points = Sobol::<u16>::new_with_resolution(DIMS, &params, Some(res)).collect::<Vec<_>>();
+
+        let mut seq = Sobol::<u16>::new_with_resolution(DIMS, &params, Some(res));
+        let mut buf = vec![0u16; 5 * DIMS];
+        for batch in points.chunks(5) {
+            assert_eq!(seq.fill_columns(&mut buf, 5), batch.len());
+            for (i, point) in batch.iter().enumerate() {
+                for (dim, v) in point.iter().enumerate() {
+                    assert_eq!(buf[dim * 5 + i], *v);
+                }
+            }
+        }
+        assert_eq!(seq.fill_columns(&mut buf, 5), 0);
+    }
+}
+
+/** Column batches stop at the end of the sequence */
+#[test] fn test_next_columns() {
+    let params = JoeKuoD6::minimal();
+    let points = Sobol::<u8>::new(DIMS, &params).collect::<Vec<_>>();
+
+    let mut seq = Sobol::<u8>::new(DIMS, &params);
+    let columns = seq.next_columns(1000);
+    assert_eq!(columns.len(), DIMS);
+    for (dim, column) in columns.iter().enumerate() {
+        assert_eq!(*column, points.iter().map(|p| p[dim]).collect::<Vec<_>>());
+    }
+    assert_eq!(seq.next_columns(10), vec![Vec::<u8>::new(); DIMS]);
+}
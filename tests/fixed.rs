@@ -0,0 +1,58 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** The number of points to compare */
+const N: usize = 2048;
+
+
+/** Fixed-dimension sequences agree with their dynamic counterparts */
+#[test] fn test_matches_dynamic() {
+    let params = JoeKuoD6::minimal();
+
+    let fixed = SobolN::<f32, 2>::new(&params).take(N).map(|p| p.to_vec()).collect::<Vec<_>>();
+    assert_eq!(fixed, Sobol::<f32>::new(2, &params).take(N).collect::<Vec<_>>());
+
+    let fixed = SobolN::<u16, 3>::new(&params).take(N).map(|p| p.to_vec()).collect::<Vec<_>>();
+    assert_eq!(fixed, Sobol::<u16>::new(3, &params).take(N).collect::<Vec<_>>());
+
+    let fixed = SobolN::<f64, 4>::new_with_resolution(&params, Some(20)).take(N).map(|p| p.to_vec()).collect::<Vec<_>>();
+    assert_eq!(fixed, Sobol::<f64>::new_with_resolution(4, &params, Some(20)).take(N).collect::<Vec<_>>());
+}
+
+/** Scrambled fixed-dimension sequences agree with their dynamic counterparts */
+#[test] fn test_scrambled() {
+    let params = JoeKuoD6::minimal();
+    for scrambling in &[Scrambling::Owen(1), Scrambling::FastOwen(2), Scrambling::Affine(3), Scrambling::Rotation(4)] {
+        let fixed = SobolN::<u32, 3>::new(&params)
+            .scrambled(*scrambling)
+            .take(N)
+            .map(|p| p.to_vec())
+            .collect::<Vec<_>>();
+        let dynamic = Sobol::<u32>::new(3, &params)
+            .scrambled(*scrambling)
+            .take(N)
+            .collect::<Vec<_>>();
+        assert_eq!(fixed, dynamic);
+    }
+}
+
+/** Random access and skipping agree with iteration */
+#[test] fn test_random_access() {
+    let params = JoeKuoD6::minimal();
+    let seq = SobolN::<f64, 3>::new(&params).scrambled(Scrambling::Linear(8));
+    let expected = seq.clone().take(N).collect::<Vec<_>>();
+
+    for (i, point) in expected.iter().enumerate() {
+        assert_eq!(seq.point_at(i as u128).as_ref(), Some(point));
+    }
+
+    let mut skipped = seq.clone();
+    skipped.skip_to(1000);
+    assert_eq!(skipped.next().as_ref(), Some(&expected[1000]));
+    assert_eq!(skipped.nth(10).as_ref(), Some(&expected[1011]));
+}
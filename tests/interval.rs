@@ -0,0 +1,75 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** Checks whether a value lies within an interval */
+type Check = fn(f64) -> bool;
+
+/** The modes under test, along with checks of their endpoints */
+const MODES: [(Interval, Check); 3] = [
+    (Interval::ClosedOpen, |v| (0.0 .. 1.0).contains(&v)),
+    (Interval::Open, |v| v > 0.0 && v < 1.0),
+    (Interval::OpenClosed, |v| v > 0.0 && v <= 1.0)
+];
+
+
+/** The extreme values of every resolution are rendered within the interval of each mode */
+#[test] fn test_interval_endpoints() {
+    for (interval, check) in MODES {
+        for res in 1 ..= f32::MAX_RESOLUTION {
+            let max = u32::MAX << (32 - res);
+            for val in [0, 1 << (32 - res), max - (1 << (32 - res)), max] {
+                let v = f32::render_interval(val, res, interval);
+                assert!(check(v as f64), "{:?} rendered {} as {} at resolution {}", interval, val, v, res);
+            }
+        }
+        for res in 1 ..= f64::MAX_RESOLUTION {
+            let max = u64::MAX << (64 - res);
+            for val in [0, 1 << (64 - res), max - (1 << (64 - res)), max] {
+                let v = f64::render_interval(val, res, interval);
+                assert!(check(v), "{:?} rendered {} as {} at resolution {}", interval, val, v, res);
+            }
+        }
+    }
+}
+
+/** Each mode renders values exactly as the cell offset from the unit interval */
+#[test] fn test_interval_offsets() {
+    let val = 0xdead_beef_u32 & (u32::MAX << 8);
+    let k = (val >> 8) as f64;
+    assert_eq!(f32::render_interval(val, 24, Interval::ClosedOpen) as f64, k / 16_777_216.0);
+    assert_eq!(f32::render_interval(val, 24, Interval::OpenClosed) as f64, (k + 1.0) / 16_777_216.0);
+    assert_eq!(f32::render_interval(val, 24, Interval::Open) as f64, ((k / 2.0).floor() + 0.5) / 8_388_608.0);
+    assert_eq!(f32::render_interval(val, 20, Interval::Open) as f64, ((k / 16.0).floor() + 0.5) / 1_048_576.0);
+}
+
+/** Every point of complete low-resolution sequences, including scrambled ones, lies within the interval */
+#[test] fn test_interval_sequences() {
+    let params = JoeKuoD6::minimal();
+    for (interval, check) in MODES {
+        for scrambling in [Scrambling::None, Scrambling::Owen(7), Scrambling::Rotation(7)] {
+            let seq = Sobol::<f32>::builder(&params)
+                .dims(8)
+                .resolution(12)
+                .interval(interval)
+                .scrambling(scrambling)
+                .build()
+                .unwrap();
+            assert!(seq.flatten().all(|v| check(v as f64)));
+
+            let mut seq = Sobol::<f64>::builder(&params)
+                .dims(8)
+                .interval(interval)
+                .scrambling(scrambling)
+                .build()
+                .unwrap();
+            let mut buf = vec![0f64; 8 * 4096];
+            seq.fill(&mut buf, 4096);
+            assert!(buf.into_iter().all(check));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+use std::iter::FusedIterator;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 5;
+
+
+/** The size hint and length track the number of remaining points exactly */
+#[test] fn test_size_hint() {
+    let params = JoeKuoD6::minimal();
+    let mut seq = Sobol::<f32>::new_with_resolution(DIMS, &params, Some(10));
+    let len = seq.len();
+    assert_eq!(len, seq.clone().fold(0, |n, _| n + 1));
+    assert_eq!(seq.size_hint(), (len, Some(len)));
+
+    seq.next();
+    seq.nth(99);
+    assert_eq!(seq.len(), len - 101);
+    assert_eq!(seq.clone().collect::<Vec<_>>().capacity(), len - 101);
+
+    seq.skip_to(u128::MAX);
+    assert_eq!(seq.size_hint(), (0, Some(0)));
+
+    let seq = Sobol::<u64>::new(DIMS, &params);
+    assert_eq!(seq.size_hint(), (usize::MAX, None));
+}
+
+/** `count` and `last` agree with exhaustive iteration without generating every point */
+#[test] fn test_count_last() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<u16>::new(DIMS, &params).scrambled(Scrambling::Owen(5));
+    let all = seq.clone().collect::<Vec<_>>();
+    assert_eq!(seq.clone().count(), all.len());
+    assert_eq!(seq.clone().last(), all.last().cloned());
+
+    let mut seq = seq;
+    seq.skip_to(u128::MAX);
+    assert_eq!(seq.clone().count(), 0);
+    assert_eq!(seq.last(), None);
+
+    let seq = Sobol::<u64>::new(DIMS, &params);
+    assert_eq!(seq.last(), Sobol::<u64>::new(DIMS, &params).point_at(u64::MAX as u128));
+
+    let seq = SobolN::<i8, 3>::new(&params);
+    let all = seq.clone().collect::<Vec<_>>();
+    assert_eq!(seq.size_hint(), (all.len(), Some(all.len())));
+    assert_eq!(seq.clone().count(), all.len());
+    assert_eq!(seq.last(), all.last().cloned());
+}
+
+/** Exhausted sequences continue to yield `None` */
+#[test] fn test_fused() {
+    fn assert_fused<I: FusedIterator>(_: &I) {}
+
+    let params = JoeKuoD6::minimal();
+    let mut seq = Sobol::<u8>::new(DIMS, &params);
+    assert_fused(&seq);
+    seq.by_ref().for_each(drop);
+    assert!((0 .. 10).all(|_| seq.next().is_none()));
+}
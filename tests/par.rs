@@ -0,0 +1,36 @@
+#![cfg(all(feature = "rayon", feature = "params-minimal"))]
+
+extern crate sobol;
+extern crate rayon;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+use rayon::prelude::*;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 10;
+
+
+/** Parallel iteration yields the same points, in the same order, as sequential iteration */
+#[test] fn test_par_iter() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<f64>::new(DIMS, &params).scrambled(Scrambling::Owen(12));
+    let expected = seq.clone().take(5000).collect::<Vec<_>>();
+
+    let points = seq.par_iter().with_max_len(37).take(5000).collect::<Vec<_>>();
+    assert_eq!(points, expected);
+}
+
+/** Parallel iteration begins at the current position and visits every remaining point */
+#[test] fn test_par_iter_remaining() {
+    let params = JoeKuoD6::minimal();
+    let mut seq = Sobol::<u16>::new(DIMS, &params);
+    seq.skip_to(60_000);
+    let expected = seq.clone().collect::<Vec<_>>();
+
+    assert_eq!(seq.par_iter().len(), expected.len());
+    assert_eq!((&seq).into_par_iter().with_max_len(100).collect::<Vec<_>>(), expected);
+    assert_eq!(seq.par_iter().rev().collect::<Vec<_>>(), expected.into_iter().rev().collect::<Vec<_>>());
+}
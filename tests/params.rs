@@ -1,10 +1,26 @@
 extern crate sobol;
+#[cfg(all(feature = "gzip", feature = "params-minimal"))]
+extern crate libflate;
 
 use crate::sobol::*;
 use crate::sobol::params::*;
 
+use std::fs;
+use std::io;
+#[cfg(all(feature = "gzip", feature = "params-minimal"))]
+use std::fs::File;
+#[cfg(all(feature = "gzip", feature = "params-minimal"))]
+use std::io::Read;
+#[cfg(all(feature = "gzip", feature = "params-minimal"))]
+use libflate::gzip::Decoder;
+
+
+/** The embedded parameter file supporting up to 100 dimensions */
+const MINIMAL_GZ: &str = "./src/params/data/new-joe-kuo-6.100.gz";
+
 
 /** Loads parameter data and verifies a few values */
+#[cfg(feature = "params-standard")]
 #[test] fn test_load_params() {
 
     /* Ensure at least 1000 parameter records loaded */
@@ -18,6 +34,7 @@ use crate::sobol::params::*;
 }
 
 /** Initializes direction values and compares them to an external reference */
+#[cfg(feature = "params-standard")]
 #[test] fn test_direction_vals() {
 
     /* Compute direction values for first 6 dimensions */
@@ -25,7 +42,7 @@ use crate::sobol::params::*;
     let dir_vals = Sobol::<u32>::init_direction_vals(6, 32, &params);
 
     /* Reference direction values for a 32-bit sequence with 6 dimensions */
-    let ref_dir_vals = vec![
+    let ref_dir_vals = [
         vec![ 2147483648, 1073741824,  536870912,  268435456,  134217728,   67108864,   33554432,   16777216,
                  8388608,    4194304,    2097152,    1048576,     524288,     262144,     131072,      65536,
                    32768,      16384,       8192,       4096,       2048,       1024,        512,        256,
@@ -59,3 +76,187 @@ use crate::sobol::params::*;
     assert!(dir_vals[4] == ref_dir_vals[4]);
     assert!(dir_vals[5] == ref_dir_vals[5]);
 }
+
+/** Parameters load at runtime from plain and gzipped files, strings and readers */
+#[cfg(all(feature = "gzip", feature = "params-minimal"))]
+#[test] fn test_load_runtime_params() {
+    let mut text = String::new();
+    Decoder::new(File::open(MINIMAL_GZ).unwrap()).unwrap().read_to_string(&mut text).unwrap();
+
+    let path = std::env::temp_dir().join(format!("sobol-params-{}.txt", std::process::id()));
+    fs::write(&path, &text).unwrap();
+    let loaded = [
+        JoeKuoD6::from_path(MINIMAL_GZ).unwrap(),
+        JoeKuoD6::from_path(&path).unwrap(),
+        JoeKuoD6::from_reader(text.as_bytes()).unwrap(),
+        text.parse::<JoeKuoD6>().unwrap()
+    ];
+    fs::remove_file(&path).unwrap();
+
+    let expected = Sobol::<u32>::new(100, &JoeKuoD6::minimal()).take(100).collect::<Vec<_>>();
+    for params in loaded.iter() {
+        assert_eq!(params.max_dims, 100);
+        assert_eq!(Sobol::<u32>::new(100, params).take(100).collect::<Vec<_>>(), expected);
+    }
+}
+
+/** Headers and blank lines are optional while malformed lines are reported */
+#[test] fn test_parse_params() {
+    let params = "2 1 0 1\n\n3 2 1 1 3\n".parse::<JoeKuoD6>().unwrap();
+    assert_eq!(params.max_dims, 3);
+    assert_eq!(params.get_dim(3).m(1), 3);
+
+    for text in ["d s a m_i\n2 1 0 x\n", "2 2 0 1\n", "2 1 0 1\nd s a m_i\n"] {
+        assert!(matches!(text.parse::<JoeKuoD6>(), Err(ParamsError::Format { .. })));
+    }
+    match JoeKuoD6::from_path("./no/such/file") {
+        Err(ParamsError::Io(err)) => assert_eq!(err.kind(), io::ErrorKind::NotFound),
+        _ => panic!("Expected an I/O error")
+    }
+}
+
+/** Format errors report the line, column and offending token */
+#[test] fn test_params_error_position() {
+    let format_err = |text: &str| match text.parse::<JoeKuoD6>() {
+        Err(ParamsError::Format { line, column, token, .. }) => (line, column, token),
+        _ => panic!("Expected a format error")
+    };
+    assert_eq!(format_err("d s a m_i\n2 1 0 1\n3  2 1 1 x3\n"), (3, 10, "x3".to_string()));
+    assert_eq!(format_err("2 1 0 1 3\n"), (1, 9, "3".to_string()));
+    assert_eq!(format_err("2 1 0 1\n3 2 1 1 \n"), (2, 8, String::new()));
+    assert_eq!(format_err("2 -1 0\n"), (1, 3, "-1".to_string()));
+
+    let err = JoeKuoD6Dim::try_parse("4 2 1 1 y").err().unwrap();
+    assert_eq!(err.to_string(), "Invalid parameters at line 1, column 9 ('y'): invalid initial direction value m_i");
+}
+
+/** Corrupt gzip input is reported as a decompression error */
+#[test] fn test_params_decompression_error() {
+    let mut bytes = fs::read(MINIMAL_GZ).unwrap();
+    bytes.truncate(bytes.len() / 2);
+    assert!(matches!(JoeKuoD6::from_reader(&bytes[..]), Err(ParamsError::Decompression(_))));
+
+    let header = [0x1f, 0x8b, 0xff, 0x00];
+    assert!(matches!(JoeKuoD6::from_reader(&header[..]), Err(ParamsError::Decompression(_))));
+}
+
+/** The embedded parameters are valid while defects of hand-edited parameters are each reported */
+#[cfg(all(feature = "params-minimal", feature = "params-extended"))]
+#[test] fn test_validate_params() {
+    assert_eq!(JoeKuoD6::minimal().validate(), Ok(()));
+    assert_eq!(JoeKuoD6::extended().validate(), Ok(()));
+
+    let text = "2 1 0 1\n3 2 1 1 3\n4 3 1 1 3 1\n5 3 0 1 3 1\n6 3 1 2 9 1\n8 3 5 1 3 1\n";
+    let violations = text.parse::<JoeKuoD6>().unwrap().validate().err().unwrap();
+    let kinds = violations.iter().map(|v| (v.dim, v.kind.clone())).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![
+        (5, ViolationKind::NotPrimitive),
+        (6, ViolationKind::EvenDirectionValue { i: 1 }),
+        (6, ViolationKind::DirectionValueTooLarge { i: 1 }),
+        (6, ViolationKind::DirectionValueTooLarge { i: 2 }),
+        (6, ViolationKind::DuplicatePolynomial { dim: 4 }),
+        (7, ViolationKind::DimensionMismatch { d: 8 }),
+        (7, ViolationKind::CoefficientOutOfRange),
+        (7, ViolationKind::DuplicatePolynomial { dim: 4 })
+    ]);
+    assert_eq!(violations[0].to_string(), "Dimension 5: polynomial is not primitive");
+}
+
+/** Custom parameters with more or fewer initial direction values than their degree are reported */
+#[test] fn test_validate_direction_value_count() {
+    let params = CustomParams(vec![
+        CustomDim { d: 2, s: 1, a: 0, m: vec![1] },
+        CustomDim { d: 3, s: 2, a: 1, m: vec![1] },
+        CustomDim { d: 4, s: 3, a: 1, m: vec![1, 3, 1, 7] }]);
+
+    let violations = params.validate().err().unwrap();
+    let kinds = violations.iter().map(|v| (v.dim, v.kind.clone())).collect::<Vec<_>>();
+    assert_eq!(kinds, vec![
+        (3, ViolationKind::DirectionValueCount { s: 2, values: 1 }),
+        (4, ViolationKind::DirectionValueCount { s: 3, values: 4 })
+    ]);
+    assert_eq!(violations[0].to_string(), "Dimension 3: expected 2 initial direction values for degree 2 but found 1");
+}
+
+/** Packed parameters match those of the text tables from which they are generated */
+#[cfg(all(feature = "gzip", feature = "params-minimal", feature = "params-standard", feature = "params-extended"))]
+#[test] fn test_packed_params() {
+    let sources = [
+        ("./src/params/data/new-joe-kuo-6.100.gz", PackedParams::minimal()),
+        ("./src/params/data/new-joe-kuo-6.1000.gz", PackedParams::standard()),
+        ("./src/params/data/new-joe-kuo-6.21201.gz", PackedParams::extended())];
+
+    for (path, packed) in sources.iter() {
+        let text = JoeKuoD6::from_path(path).unwrap();
+        let unpacked = packed.unpack();
+        assert_eq!(packed.max_dims(), text.max_dims);
+        for (a, b) in unpacked.dim_params.iter().zip(text.dim_params.iter()) {
+            assert_eq!((a.d, a.a, &a.m), (b.d, b.a, &b.m));
+        }
+    }
+
+    let expected = Sobol::<u32>::new(100, &JoeKuoD6::minimal()).take(100).collect::<Vec<_>>();
+    assert_eq!(Sobol::<u32>::new(100, &PackedParams::extended()).take(100).collect::<Vec<_>>(), expected);
+}
+
+/** Malformed packed parameters are reported */
+#[test] fn test_packed_params_errors() {
+    let packed_err = |bytes: &'static [u8]| match PackedParams::from_bytes(bytes) {
+        Err(ParamsError::Packed { offset, .. }) => offset,
+        _ => panic!("Expected a packed parameters error")
+    };
+    assert_eq!(packed_err(b"2 1 0 1"), 0);
+    assert_eq!(packed_err(b"JKD6\x01\x01\x00\x00\x00\x00\x01\x00\x00\x00"), 9);
+    assert_eq!(packed_err(b"JKD6\x01\x01\x00\x00\x00\x02\x03\x00\x00\x00\x0f"), 15);
+    assert_eq!(packed_err(b"JKD6\x01\x01\x00\x00\x00\x01\x02\x00\x00\x00\x03\x00"), 15);
+
+    let packed = PackedParams::from_bytes(b"JKD6\x01\x01\x00\x00\x00\x02\x02\x00\x00\x00\xf6").unwrap();
+    assert_eq!(packed.max_dims(), 3);
+    assert_eq!((packed.get_dim(3).coefficient(0), packed.get_dim(3).m(0), packed.get_dim(3).m(1)), (1, 1, 3));
+    assert_eq!((packed.get_dim(2).coefficient(0), packed.get_dim(2).m(0), packed.get_dim(2).m(1)), (0, 1, 1));
+}
+
+
+/** Parameters of a custom type which may hold a different number of `m_i` than their degree */
+struct CustomParams(Vec<CustomDim>);
+
+struct CustomDim {
+    d: u16,
+    s: usize,
+    a: u32,
+    m: Vec<u32>
+}
+
+impl SobolParams<u32> for CustomParams {
+
+    fn get_dim(&self, dim: usize) -> &dyn ParamDimension<u32> {
+        &self.0[dim - 2]
+    }
+
+    fn max_dims(&self) -> usize {
+        self.0.len() + 1
+    }
+}
+
+impl ParamDimension<u32> for CustomDim {
+
+    fn d(&self) -> u16 {
+        self.d
+    }
+
+    fn s(&self) -> usize {
+        self.s
+    }
+
+    fn coefficient(&self, i: usize) -> u32 {
+        (self.a >> i) & 1
+    }
+
+    fn m(&self, i: usize) -> u32 {
+        self.m[i]
+    }
+
+    fn m_len(&self) -> usize {
+        self.m.len()
+    }
+}
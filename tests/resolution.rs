@@ -0,0 +1,58 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 6;
+
+
+/** Sequences have exactly 2^res points, the first dimension of which covers every value */
+#[test] fn test_full_length() {
+    let params = JoeKuoD6::minimal();
+    let mut first = Sobol::<u8>::new(DIMS, &params).map(|p| p[0]).collect::<Vec<_>>();
+    assert_eq!(first.len(), 256);
+    first.sort_unstable();
+    assert_eq!(first, (0 ..= 255).collect::<Vec<u8>>());
+
+    assert_eq!(Sobol::<i16>::new_with_resolution(DIMS, &params, Some(12)).count(), 1 << 12);
+    assert_eq!(Sobol::<u128>::new(DIMS, &params).max_len, u128::MAX);
+}
+
+/** Computing at a higher internal resolution is equivalent to truncating a wider sequence */
+#[test] fn test_internal_resolution() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<u8>::builder(&params).dims(DIMS).internal_resolution(16).build().unwrap();
+    let wide = Sobol::<u16>::new(DIMS, &params);
+    assert_eq!(seq.len(), 1 << 16);
+    for (p, q) in seq.zip(wide) {
+        assert_eq!(p, q.iter().map(|v| (v >> 8) as u8).collect::<Vec<_>>());
+    }
+
+    /* Beyond the width of the internal type */
+    let seq = Sobol::<u16>::builder(&params)
+        .dims(DIMS)
+        .resolution(12)
+        .internal_resolution(40)
+        .start((1 << 39) - 500)
+        .build()
+        .unwrap();
+    let mut wide = Sobol::<u64>::new_with_resolution(DIMS, &params, Some(40));
+    wide.skip_to((1 << 39) - 500);
+    for (p, q) in seq.zip(wide).take(1000) {
+        assert_eq!(p, q.iter().map(|v| (v >> 48) as u16 & 0xfff0).collect::<Vec<_>>());
+    }
+}
+
+/** Internal resolutions below the resolution or beyond the maximum are rejected */
+#[test] fn test_internal_resolution_errors() {
+    let params = JoeKuoD6::minimal();
+    assert_eq!(Sobol::<f32>::builder(&params).dims(2).resolution(20).internal_resolution(19).build().err(),
+               Some(SobolError::UnsupportedInternalResolution { internal_resolution: 19, resolution: 20, max_internal_resolution: 63 }));
+    assert_eq!(Sobol::<u8>::builder(&params).dims(2).internal_resolution(64).build().err(),
+               Some(SobolError::UnsupportedInternalResolution { internal_resolution: 64, resolution: 8, max_internal_resolution: 63 }));
+    assert!(Sobol::<u128>::builder(&params).dims(2).internal_resolution(128).build().is_ok());
+}
@@ -0,0 +1,298 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 8;
+
+/** The log2 of the number of points to consider */
+const M: usize = 10;
+
+
+/** Scrambled sequences are reproducible per seed and differ across seeds */
+#[test] fn test_owen_seeding() {
+    let params = JoeKuoD6::minimal();
+    let gen = |scrambling| Sobol::<f64>::new(DIMS, &params)
+        .scrambled(scrambling)
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_eq!(gen(Scrambling::Owen(7)), gen(Scrambling::Owen(7)));
+    assert_ne!(gen(Scrambling::Owen(7)), gen(Scrambling::Owen(8)));
+    assert_ne!(gen(Scrambling::Owen(7)), gen(Scrambling::None));
+}
+
+/** Each dimension of the first 2^m scrambled points remains stratified */
+#[test] fn test_owen_stratified() {
+    let params = JoeKuoD6::minimal();
+    let points = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::Owen(42))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    for dim in 0 .. DIMS {
+        assert_net(&points, &[dim], M, 0);
+    }
+}
+
+/** The first two dimensions of the scrambled sequence remain a (0,m,2)-net */
+#[test] fn test_owen_net() {
+    let params = JoeKuoD6::minimal();
+    let points = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::Owen(1234))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_net(&points, &[0, 1], M, 0);
+}
+
+/** Random access agrees with iteration for scrambled sequences */
+#[test] fn test_owen_point_at() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<u16>::new(DIMS, &params).scrambled(Scrambling::Owen(3));
+
+    for (i, point) in seq.clone().take(1 << M).enumerate() {
+        assert_eq!(seq.point_at(i as u128), Some(point));
+    }
+}
+
+/** Fast Owen scrambled sequences are reproducible per seed and differ across seeds */
+#[test] fn test_fast_owen_seeding() {
+    let params = JoeKuoD6::minimal();
+    let gen = |scrambling| Sobol::<f32>::new(DIMS, &params)
+        .scrambled(scrambling)
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_eq!(gen(Scrambling::FastOwen(7)), gen(Scrambling::FastOwen(7)));
+    assert_ne!(gen(Scrambling::FastOwen(7)), gen(Scrambling::FastOwen(8)));
+    assert_ne!(gen(Scrambling::FastOwen(7)), gen(Scrambling::Owen(7)));
+}
+
+/** The first two dimensions of the fast Owen scrambled sequence remain a (0,m,2)-net */
+#[test] fn test_fast_owen_net() {
+    let params = JoeKuoD6::minimal();
+    let points = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::FastOwen(99))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_net(&points, &[0, 1], M, 0);
+    for dim in 0 .. DIMS {
+        assert_net(&points, &[dim], M, 0);
+    }
+}
+
+/** Fast Owen scrambling preserves stratification for every internal type width */
+#[test] fn test_fast_owen_widths() {
+    let params = JoeKuoD6::minimal();
+    let scrambling = Scrambling::FastOwen(5);
+
+    let u8s = Sobol::<u8>::new(DIMS, &params).scrambled(scrambling).take(1 << 7);
+    let u16s = Sobol::<u16>::new(DIMS, &params).scrambled(scrambling).take(1 << M);
+    let u64s = Sobol::<u64>::new(DIMS, &params).scrambled(scrambling).take(1 << M);
+    let u128s = Sobol::<u128>::new(DIMS, &params).scrambled(scrambling).take(1 << M);
+
+    let u8s = u8s.map(|p| p.iter().map(|v| u32::from(*v) << 24).collect()).collect::<Vec<_>>();
+    let u16s = u16s.map(|p| p.iter().map(|v| u32::from(*v) << 16).collect()).collect::<Vec<_>>();
+    let u64s = u64s.map(|p| p.iter().map(|v| (*v >> 32) as u32).collect()).collect::<Vec<_>>();
+    let u128s = u128s.map(|p| p.iter().map(|v| (*v >> 96) as u32).collect()).collect::<Vec<_>>();
+
+    for dim in 0 .. DIMS {
+        assert_net(&u8s, &[dim], 7, 0);
+        assert_net(&u16s, &[dim], M, 0);
+        assert_net(&u64s, &[dim], M, 0);
+        assert_net(&u128s, &[dim], M, 0);
+    }
+}
+
+/** A digital shift XORs every point of a dimension with the same value */
+#[test] fn test_digital_shift() {
+    let params = JoeKuoD6::minimal();
+    let plain = Sobol::<u32>::new(DIMS, &params).take(1 << M).collect::<Vec<_>>();
+    let shifted = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::DigitalShift(11))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    for (p, s) in plain.iter().zip(&shifted) {
+        for dim in 0 .. DIMS {
+            assert_eq!(p[dim] ^ s[dim], shifted[0][dim]);
+        }
+    }
+    assert_net(&shifted, &[0, 1], M, 0);
+}
+
+/** Digitally shifted floats stay within the unit interval at full resolution */
+#[test] fn test_digital_shift_float_range() {
+    let params = JoeKuoD6::minimal();
+    for seed in 0 .. 64 {
+        let seq = Sobol::<f32>::new(DIMS, &params).scrambled(Scrambling::DigitalShift(seed));
+        assert!(seq.take(1 << M).flatten().all(|v| (0.0 .. 1.0).contains(&v)));
+    }
+}
+
+/** Replicates are reproducible, mutually distinct and start at the same position */
+#[test] fn test_shifted_replicates() {
+    let params = JoeKuoD6::minimal();
+    let mut seq = Sobol::<f64>::new(DIMS, &params);
+    seq.skip_to(10);
+
+    let first = |reps: Vec<Sobol<f64>>| reps.into_iter().map(|mut r| r.next().unwrap()).collect::<Vec<_>>();
+    let reps = first(seq.shifted_replicates(8, 2020));
+
+    assert_eq!(reps.len(), 8);
+    assert_eq!(reps, first(seq.shifted_replicates(8, 2020)));
+    for (i, a) in reps.iter().enumerate() {
+        assert!(reps.iter().skip(i + 1).all(|b| a != b));
+    }
+}
+
+/** Linearly scrambled sequences preserve the origin and the net structure */
+#[test] fn test_linear() {
+    let params = JoeKuoD6::minimal();
+    let plain = Sobol::<u32>::new(DIMS, &params).take(1 << M).collect::<Vec<_>>();
+    let points = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::Linear(17))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_ne!(points, plain);
+    assert_eq!(points[0], vec![0; DIMS]);
+    assert_net(&points, &[0, 1], M, 0);
+    for dim in 0 .. DIMS {
+        assert_net(&points, &[dim], M, 0);
+    }
+}
+
+/** Affine scrambling is a linear scrambling followed by a digital shift */
+#[test] fn test_affine() {
+    let params = JoeKuoD6::minimal();
+    let points = Sobol::<u32>::new(DIMS, &params)
+        .scrambled(Scrambling::Affine(17))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    assert_ne!(points[0], vec![0; DIMS]);
+    assert_net(&points, &[0, 1], M, 0);
+
+    let linear = points.iter()
+        .map(|p| p.iter().zip(&points[0]).map(|(v, s)| v ^ s).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_net(&linear, &[0, 1], M, 0);
+}
+
+/** Replacing a linear scrambling restores the original direction values */
+#[test] fn test_linear_replaced() {
+    let params = JoeKuoD6::minimal();
+    let plain = Sobol::<u64>::new(DIMS, &params).take(1 << M).collect::<Vec<_>>();
+    let linear = Sobol::<u64>::new(DIMS, &params).scrambled(Scrambling::Linear(5));
+
+    let mut restored = linear.clone().scrambled(Scrambling::Affine(9)).scrambled(Scrambling::None);
+    assert_eq!(restored.clone().take(1 << M).collect::<Vec<_>>(), plain);
+
+    restored.skip_to(100);
+    let rescrambled = restored.scrambled(Scrambling::Linear(5));
+    assert_eq!(rescrambled.take(1 << M).collect::<Vec<_>>(), linear.skip(100).take(1 << M).collect::<Vec<_>>());
+}
+
+/** A rotation adds the same offset, modulo one, to every point of a dimension */
+#[test] fn test_rotation() {
+    let params = JoeKuoD6::minimal();
+    let plain = Sobol::<f64>::new(DIMS, &params).take(1 << M).collect::<Vec<_>>();
+    let rotated = Sobol::<f64>::new(DIMS, &params)
+        .scrambled(Scrambling::Rotation(23))
+        .take(1 << M)
+        .collect::<Vec<_>>();
+
+    let offsets = &rotated[0];
+    for (p, r) in plain.iter().zip(&rotated) {
+        for dim in 0 .. DIMS {
+            let diff = (r[dim] - (p[dim] + offsets[dim]).fract()).abs();
+            assert!(diff.min(1.0 - diff) < 1e-15);
+        }
+    }
+}
+
+/** Rotated floats stay within the unit interval at full resolution */
+#[test] fn test_rotation_float_range() {
+    let params = JoeKuoD6::minimal();
+    for seed in 0 .. 64 {
+        let seq = Sobol::<f32>::new(DIMS, &params).scrambled(Scrambling::Rotation(seed));
+        assert!(seq.take(1 << M).flatten().all(|v| (0.0 .. 1.0).contains(&v)));
+    }
+}
+
+
+/** Shifts, rotations and Owen scrambling randomize low resolution sequences within their cells of width 2^-res */
+#[test] fn test_low_resolution_offsets() {
+    let params = JoeKuoD6::minimal();
+    let point_set = |scrambling: Scrambling, interval: Interval| {
+        let seq = Sobol::<f64>::builder(&params).dims(1).resolution(4).interval(interval).scrambling(scrambling).build().unwrap();
+        let mut vals = seq.map(|p| p[0]).collect::<Vec<_>>();
+        vals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        vals
+    };
+
+    let schemes: [fn(u64) -> Scrambling; 5] = [
+        Scrambling::Rotation, Scrambling::DigitalShift, Scrambling::Affine, Scrambling::Owen, Scrambling::FastOwen];
+    for scheme in schemes.iter() {
+        let sets = (0 .. 4).map(|seed| point_set(scheme(seed), Interval::ClosedOpen)).collect::<Vec<_>>();
+        for (i, set) in sets.iter().enumerate() {
+            assert_eq!(set.len(), 16);
+            assert!(set.iter().all(|v| (0.0 .. 1.0).contains(v)));
+            assert!(set.iter().enumerate().all(|(k, v)| (v * 16.0).floor() == k as f64));
+            assert!(sets.iter().skip(i + 1).all(|other| other != set));
+        }
+        for seed in 0 .. 64 {
+            assert!(point_set(scheme(seed), Interval::Open).iter().all(|v| *v > 0.0 && *v < 1.0));
+        }
+    }
+
+    let seq = Sobol::<f32>::new_with_resolution(1, &params, Some(4));
+    let reps = seq.shifted_replicates(4, 7).into_iter()
+        .map(|r| r.map(|p| p[0]).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    for (i, rep) in reps.iter().enumerate() {
+        assert!(reps.iter().skip(i + 1).all(|other| other[0] != rep[0]));
+    }
+}
+
+/**
+ * Asserts that the given `u32` points form a (t,m,s)-net over the selected dimensions, i.e. that
+ * every elementary interval of volume 2^(t-m) contains exactly 2^t points.
+ */
+fn assert_net(points: &[Vec<u32>], dims: &[usize], m: usize, t: usize) {
+    for_each_split(m - t, dims.len(), &mut |split| {
+        let mut counts = vec![0usize; 1 << (m - t)];
+        for p in points.iter().take(1 << m) {
+            let cell = dims.iter().zip(split).fold(0usize, |cell, (dim, bits)| {
+                (cell << bits) | (if *bits == 0 { 0 } else { (p[*dim] >> (32 - bits)) as usize })
+            });
+            counts[cell] += 1;
+        }
+        assert!(counts.iter().all(|c| *c == 1 << t), "not a net for split {:?}", split);
+    });
+}
+
+/** Visits every way of distributing `n` bits across `k` dimensions */
+fn for_each_split(n: usize, k: usize, f: &mut dyn FnMut(&[usize])) {
+    fn recurse(n: usize, k: usize, split: &mut Vec<usize>, f: &mut dyn FnMut(&[usize])) {
+        if split.len() + 1 == k {
+            split.push(n);
+            f(split);
+            split.pop();
+        } else {
+            for bits in 0 ..= n {
+                split.push(bits);
+                recurse(n - bits, k, split, f);
+                split.pop();
+            }
+        }
+    }
+    recurse(n, k, &mut vec![], f);
+}
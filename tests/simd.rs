@@ -0,0 +1,37 @@
+#![cfg(feature = "params-standard")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+
+/** A dimensionality which exercises both vectorized and remainder code paths */
+const DIMS: usize = 999;
+
+/** The number of points to compare */
+const N: usize = 300;
+
+
+/** Vectorized generation agrees with the iterator for high-dimensional sequences */
+#[test] fn test_fill_high_dims() {
+    let params = JoeKuoD6::standard();
+    fill_matches::<f32>(&params);
+    fill_matches::<f64>(&params);
+    fill_matches::<u32>(&params);
+    fill_matches::<u64>(&params);
+}
+
+
+/** Compares points written by `fill` and `fill_columns` with those produced by the iterator */
+fn fill_matches<T>(params: &JoeKuoD6)
+    where T: SobolType + Default + Clone + PartialEq + std::fmt::Debug, T::IT: LossyFrom<u32> {
+
+    let expected = Sobol::<T>::new(DIMS, params).take(N).flatten().collect::<Vec<_>>();
+    let mut buf = vec![T::default(); N * DIMS];
+    Sobol::<T>::new(DIMS, params).fill(&mut buf, N);
+    assert!(buf == expected);
+
+    Sobol::<T>::new(DIMS, params).fill_columns(&mut buf, N);
+    assert!((0 .. N * DIMS).all(|i| buf[(i % DIMS) * N + i / DIMS] == expected[i]));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "params-minimal")]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+use std::sync::Arc;
+use std::thread;
+
+
+/** The dimensionality of the sequences under test */
+const DIMS: usize = 16;
+
+/** The number of points to compare */
+const N: usize = 1024;
+
+
+/** Skipping to each index yields the same point as iterating to it */
+#[test] fn test_skip_to_matches_iteration() {
+    let params = JoeKuoD6::minimal();
+    let expected = Sobol::<u32>::new(DIMS, &params).take(N).collect::<Vec<_>>();
+
+    for (i, point) in expected.iter().enumerate() {
+        let mut seq = Sobol::<u32>::new(DIMS, &params);
+        seq.skip_to(i as u128);
+        assert_eq!(seq.next().as_ref(), Some(point), "mismatch at index {}", i);
+    }
+}
+
+/** Iteration continues correctly after skipping */
+#[test] fn test_skip_to_then_iterate() {
+    let params = JoeKuoD6::minimal();
+    let expected = Sobol::<f64>::new(DIMS, &params).skip(377).take(N).collect::<Vec<_>>();
+
+    let mut seq = Sobol::<f64>::new(DIMS, &params);
+    seq.skip_to(377);
+    assert_eq!(seq.take(N).collect::<Vec<_>>(), expected);
+}
+
+/** `nth` agrees with stepping through the sequence and exhausts it when out of range */
+#[test] fn test_nth() {
+    let params = JoeKuoD6::minimal();
+    let mut expected = Sobol::<u16>::new(DIMS, &params);
+    let mut seq = Sobol::<u16>::new(DIMS, &params);
+
+    for n in &[0, 1, 5, 100, 0, 1023] {
+        let point = (0 ..= *n).map(|_| expected.next()).last().unwrap();
+        assert_eq!(seq.nth(*n), point);
+    }
+
+    assert_eq!(seq.nth(usize::MAX), None);
+    assert_eq!(seq.next(), None);
+}
+
+/** Random access agrees with iteration and leaves the sequence untouched */
+#[test] fn test_point_at() {
+    let params = JoeKuoD6::minimal();
+    let seq = Sobol::<f32>::new(DIMS, &params);
+
+    for (i, point) in seq.clone().take(N).enumerate() {
+        assert_eq!(seq.point_at(i as u128), Some(point));
+    }
+
+    assert_eq!(seq.point_at(seq.max_len), None);
+    assert_eq!(seq.count, 0);
+}
+
+/** Points may be evaluated concurrently from a shared sequence */
+#[test] fn test_point_at_shared() {
+    let params = JoeKuoD6::minimal();
+    let seq = Arc::new(Sobol::<f64>::new(DIMS, &params));
+    let expected = Arc::new(seq.as_ref().clone().take(N).collect::<Vec<_>>());
+
+    let workers = (0 .. 4).map(|t| {
+        let (seq, expected) = (seq.clone(), expected.clone());
+        thread::spawn(move || {
+            for i in (t .. N).step_by(4) {
+                assert_eq!(seq.point_at(i as u128).as_ref(), Some(&expected[i]));
+            }
+        })
+    }).collect::<Vec<_>>();
+
+    workers.into_iter().for_each(|w| w.join().unwrap());
+}
@@ -0,0 +1,58 @@
+#![cfg(all(feature = "params-minimal", feature = "params-extended"))]
+
+extern crate sobol;
+
+use crate::sobol::*;
+use crate::sobol::params::JoeKuoD6;
+
+use std::thread;
+
+
+/** The number of points to compare */
+const N: usize = 1000;
+
+
+/** Cursors produce the same points as the sequence from which the table was made */
+#[test] fn test_cursor() {
+    let params = JoeKuoD6::minimal();
+    let seq = || Sobol::<f64>::new(10, &params).scrambled(Scrambling::Owen(9));
+    let expected = seq().take(N).collect::<Vec<_>>();
+
+    let table = SobolTable::from(seq());
+    assert_eq!(table.dims(), 10);
+    assert_eq!(table.cursor().take(N).collect::<Vec<_>>(), expected);
+    assert_eq!(table.cursor_at(500).take(N - 500).collect::<Vec<_>>(), &expected[500 ..]);
+    assert_eq!(table.point_at(123).as_ref(), Some(&expected[123]));
+
+    let mut cursor = table.cursor();
+    let mut buf = vec![0f64; 10];
+    assert!(cursor.next_into(&mut buf));
+    assert_eq!(buf, expected[0]);
+
+    let mut buf = vec![0f64; 10 * (N - 1)];
+    assert_eq!(cursor.fill(&mut buf, N - 1), N - 1);
+    assert_eq!(buf, expected[1 ..].concat());
+    assert_eq!(cursor.position(), N as u128);
+}
+
+/** Cursors begin where the sequence was positioned when the table was made */
+#[test] fn test_cursor_start() {
+    let params = JoeKuoD6::minimal();
+    let table: SobolTable<f32> = Sobol::builder(&params).dims(3).skip_zero().build().unwrap().into();
+    assert_eq!(table.cursor().position(), 1);
+    assert_eq!(table.cursor().next(), Sobol::<f32>::new(3, &params).nth(1));
+}
+
+/** Many threads iterate independently over a single shared table */
+#[test] fn test_cursor_threads() {
+    let params = JoeKuoD6::extended();
+    let table = SobolTable::from(Sobol::<u32>::new(params.max_dims, &params));
+
+    let handles = (0 .. 8u128).map(|i| {
+        let table = table.clone();
+        thread::spawn(move || table.cursor_at(i * 100).take(100).collect::<Vec<_>>())
+    }).collect::<Vec<_>>();
+
+    let points = handles.into_iter().flat_map(|h| h.join().unwrap()).collect::<Vec<_>>();
+    assert_eq!(points, table.cursor().take(800).collect::<Vec<_>>());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "params-extended")]
+
 extern crate sobol;
 extern crate libflate;
 #[macro_use] extern crate lazy_static;
@@ -268,32 +270,32 @@ lazy_static! {
  * Generates a sequence of type T and compares values to an externally generated
  * reference sequence (see 'test/data/ref_seq_*.tsv.gz')
  */
-fn validate<T>(ref_seq: &Vec<Vec<f32>>, resolution: Option<usize>) 
+fn validate<T>(ref_seq: &[Vec<f32>], resolution: Option<usize>) 
     where T: SobolType + ToFloat + Display, T::IT: LossyFrom<u32>,
 {
     let dims: usize = ref_seq[0].len();
     let params = JoeKuoD6::extended();
 
-    Sobol::<T>::new_with_resolution(dims, &params, resolution)
+    let mismatch = Sobol::<T>::new_with_resolution(dims, &params, resolution)
         .map(|s| s.iter().map(|v| v.to_float()).collect::<Vec<_>>())
         .zip(ref_seq.iter().map(|p| p.to_vec()).collect::<Vec<_>>())
         .enumerate()
-        .filter(|(_, (s, r))| s != r)
-        .take(1)
-        .for_each(|(i, (s, r))| {
-            panic!("Generated point #{} does not match point from reference sequence!\n  --> generated = {}\n  --> expected =  {}", i, point_str(&s), point_str(&r));
-        });
+        .find(|(_, (s, r))| s != r);
+
+    if let Some((i, (s, r))) = mismatch {
+        panic!("Generated point #{} does not match point from reference sequence!\n  --> generated = {}\n  --> expected =  {}", i, point_str(&s), point_str(&r));
+    }
 }
 
 /** Generates string representation of a multi-dimensional point for display */
-fn point_str<T: Display>(point: &Vec<T>) -> String {
+fn point_str<T: Display>(point: &[T]) -> String {
     format!("[{}]", point.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(","))
 }
 
 /** Loads reference sequence from gzipped tsv file */
 fn load_ref_seq(filename: &str) -> Vec<Vec<f32>> {
     let mut file = File::open(filename)
-        .expect(&format!("Can't open reference sequence file: {}", filename));
+        .unwrap_or_else(|_| panic!("Can't open reference sequence file: {}", filename));
     let mut decoder = Decoder::new(&mut file).unwrap();
     BufReader::new(&mut decoder)
         .lines()